@@ -1,9 +1,31 @@
 mod button_parameters;
 mod calendar_parameters;
 mod checkbox_list_parameters;
+mod date_time_picker_parameters;
+mod dropdown_parameters;
+mod keypad_parameters;
+mod menu_parameters;
 mod radio_list_parameters;
+mod rating_parameters;
+mod sortable_list_parameters;
+mod stepper_parameters;
+mod table_parameters;
+mod tabs_parameters;
+mod time_picker_parameters;
+mod toggle_parameters;
+mod transfer_list_parameters;
+mod tree_view_parameters;
+mod wizard_parameters;
 
 pub use self::{
     button_parameters::ButtonParameters, calendar_parameters::CalendarParameters,
-    checkbox_list_parameters::CheckboxListParameters, radio_list_parameters::RadioListParameters,
+    checkbox_list_parameters::CheckboxListParameters,
+    date_time_picker_parameters::DateTimePickerParameters, dropdown_parameters::DropdownParameters,
+    keypad_parameters::KeypadParameters, menu_parameters::MenuParameters,
+    radio_list_parameters::RadioListParameters, rating_parameters::RatingParameters,
+    sortable_list_parameters::SortableListParameters, stepper_parameters::StepperParameters,
+    table_parameters::TableParameters, tabs_parameters::TabsParameters,
+    time_picker_parameters::TimePickerParameters, toggle_parameters::ToggleParameters,
+    transfer_list_parameters::TransferListParameters, tree_view_parameters::TreeViewParameters,
+    wizard_parameters::WizardParameters,
 };
@@ -10,4 +10,8 @@ pub struct ButtonParameters {
     /// Handler to be invoked when the button is clicked
     #[darling(rename = "click")]
     pub click_handler: Path,
+    /// Confirmation question, the `click` handler is invoked only after the
+    /// click is confirmed. The widget state is required to store the pending
+    /// confirmation
+    pub confirm: Option<String>,
 }
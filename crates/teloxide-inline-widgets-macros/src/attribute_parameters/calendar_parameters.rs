@@ -6,9 +6,14 @@ use syn::Path;
 pub struct CalendarParameters {
     /// CallbackQuery data prefix to be sent with the selected day
     pub day_prefix: Option<String>,
-    /// Handler to be invoked when the day-button is clicked
+    /// Handler to be invoked when the day-button is clicked, after the day is
+    /// selected
     #[darling(rename = "day_click")]
-    pub day_click_handler: Path,
+    pub day_click_handler: Option<Path>,
+    /// Handler to be invoked when the range of days is selected, receives
+    /// `(NaiveDate, NaiveDate)`
+    #[darling(rename = "range_selected")]
+    pub range_selected_handler: Option<Path>,
     /// CallbackQuery data prefix to be sent with the selected day of the week
     pub weekday_prefix: Option<String>,
     /// Handler to be invoked when the weekday-button is clicked
@@ -24,6 +29,27 @@ pub struct CalendarParameters {
     pub prev_month: Option<String>,
     /// CallbackQuery data to be sent when the `next month` button is clicked
     pub next_month: Option<String>,
+    /// CallbackQuery data prefix to be sent with the month selected in the
+    /// months view
+    pub month_prefix: Option<String>,
+    /// CallbackQuery data prefix to be sent with the year selected in the
+    /// years view
+    pub year_prefix: Option<String>,
+    /// CallbackQuery data to be sent when the `return to the days` button of
+    /// the months and the years views is clicked
+    pub days_view: Option<String>,
+    /// CallbackQuery data to be sent when the month label is clicked
+    pub months_view: Option<String>,
+    /// CallbackQuery data to be sent when the year label is clicked
+    pub years_view: Option<String>,
+    /// CallbackQuery data to be sent when the `previous years page` button is
+    /// clicked
+    pub prev_years_page: Option<String>,
+    /// CallbackQuery data to be sent when the `next years page` button is
+    /// clicked
+    pub next_years_page: Option<String>,
     /// CallbackQuery data for empty cells
     pub noop_data: Option<String>,
+    /// Predicate `fn(NaiveDate) -> bool` for the days that can't be clicked
+    pub is_day_disabled: Option<Path>,
 }
@@ -1,4 +1,5 @@
 use darling::FromField;
+use syn::Path;
 
 /// Arguments for the `#[checkbox_list]` field attribute
 #[derive(Debug, FromField)]
@@ -6,6 +7,16 @@ use darling::FromField;
 pub struct CheckboxListParameters {
     /// CallbackQuery data prefix to be sent with the index of the clicked item
     pub prefix: String,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: Option<String>,
     /// CallbackQuery data for empty cells
     pub noop_data: Option<String>,
+    /// Minimal number of selected items
+    pub min: Option<usize>,
+    /// Maximal number of selected items
+    pub max: Option<usize>,
+    /// Handler to be invoked when an item is toggled
+    #[darling(rename = "on_toggle")]
+    pub on_toggle_handler: Option<Path>,
 }
@@ -0,0 +1,19 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[date_time_picker]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(date_time_picker))]
+pub struct DateTimePickerParameters {
+    /// CallbackQuery data prefix of the widget buttons, the data of the nested
+    /// `Calendar` and `TimePicker` widgets is derived from it
+    pub prefix: String,
+    /// CallbackQuery data for empty cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the confirm button is clicked, receives
+    /// `NaiveDateTime` and `DateTime<FixedOffset>`
+    #[darling(rename = "on_confirm")]
+    pub on_confirm_handler: Option<Path>,
+    /// Predicate `fn(NaiveDate) -> bool` for the days that can't be clicked
+    pub is_day_disabled: Option<Path>,
+}
@@ -0,0 +1,21 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[dropdown]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(dropdown))]
+pub struct DropdownParameters {
+    /// CallbackQuery data prefix to be sent with the index of the clicked item
+    pub prefix: String,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: Option<String>,
+    /// CallbackQuery data to be sent when the button with the active item is
+    /// clicked
+    pub toggle_data: Option<String>,
+    /// CallbackQuery data for empty cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the active item is changed
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
@@ -0,0 +1,16 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[keypad]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(keypad))]
+pub struct KeypadParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: String,
+    /// CallbackQuery data for the header button
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the confirm button is clicked, receives
+    /// `KeypadInput`
+    #[darling(rename = "on_confirm")]
+    pub on_confirm_handler: Option<Path>,
+}
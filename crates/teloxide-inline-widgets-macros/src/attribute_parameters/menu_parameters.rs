@@ -0,0 +1,15 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[menu]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(menu))]
+pub struct MenuParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: String,
+    /// CallbackQuery data for the breadcrumb
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the menu item is clicked
+    #[darling(rename = "on_select")]
+    pub on_select_handler: Option<Path>,
+}
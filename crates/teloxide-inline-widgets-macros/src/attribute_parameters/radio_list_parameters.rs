@@ -1,4 +1,5 @@
 use darling::FromField;
+use syn::Path;
 
 /// Arguments for the `#[radio_list]` field attribute
 #[derive(Debug, FromField)]
@@ -6,6 +7,12 @@ use darling::FromField;
 pub struct RadioListParameters {
     /// CallbackQuery data prefix to be sent with the index of the clicked item
     pub prefix: String,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: Option<String>,
     /// CallbackQuery data for empty cells
     pub noop_data: Option<String>,
+    /// Handler to be invoked when the active item is changed
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
 }
@@ -0,0 +1,17 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[rating]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(rating))]
+pub struct RatingParameters {
+    /// CallbackQuery data prefix to be sent with the number of the clicked
+    /// star
+    pub prefix: String,
+    /// Whether the click on the selected star clears the rating
+    #[darling(default)]
+    pub clearable: bool,
+    /// Handler to be invoked when the rating is changed
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
@@ -0,0 +1,15 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[sortable_list]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(sortable_list))]
+pub struct SortableListParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: String,
+    /// CallbackQuery data for empty cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the order of the items is changed
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
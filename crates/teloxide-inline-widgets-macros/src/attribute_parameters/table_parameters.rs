@@ -0,0 +1,18 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[table]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(table))]
+pub struct TableParameters {
+    /// CallbackQuery data prefix of the headers and the cells
+    pub prefix: String,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: Option<String>,
+    /// CallbackQuery data for empty and not clickable cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the cell is clicked
+    #[darling(rename = "on_click")]
+    pub on_click_handler: Option<Path>,
+}
@@ -0,0 +1,9 @@
+use darling::FromField;
+
+/// Arguments for the `#[tabs]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(tabs))]
+pub struct TabsParameters {
+    /// CallbackQuery data prefix to be sent with the index of the clicked tab
+    pub prefix: String,
+}
@@ -0,0 +1,16 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[time_picker]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(time_picker))]
+pub struct TimePickerParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: String,
+    /// CallbackQuery data for empty cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the confirm button is clicked, receives
+    /// `NaiveTime`
+    #[darling(rename = "on_confirm")]
+    pub on_confirm_handler: Option<Path>,
+}
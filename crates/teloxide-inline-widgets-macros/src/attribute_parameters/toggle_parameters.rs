@@ -0,0 +1,13 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[toggle]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(toggle))]
+pub struct ToggleParameters {
+    /// CallbackQuery data to be sent when the toggle is clicked
+    pub data: String,
+    /// Handler to be invoked when the state is switched
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
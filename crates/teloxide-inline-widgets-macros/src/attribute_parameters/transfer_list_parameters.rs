@@ -0,0 +1,19 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[transfer_list]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(transfer_list))]
+pub struct TransferListParameters {
+    /// CallbackQuery data prefix to be sent with the column, the revision of
+    /// the widget and the index of the clicked item
+    pub prefix: String,
+    /// CallbackQuery data prefix to be sent with the column and the index of
+    /// the selected page
+    pub page_prefix: Option<String>,
+    /// CallbackQuery data for empty cells
+    pub noop_data: Option<String>,
+    /// Handler to be invoked when the item is moved to the other column
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
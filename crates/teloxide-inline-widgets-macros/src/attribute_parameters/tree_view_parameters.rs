@@ -0,0 +1,14 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[tree_view]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(tree_view))]
+pub struct TreeViewParameters {
+    /// CallbackQuery data prefix to be sent with the identifier of the clicked
+    /// node
+    pub prefix: String,
+    /// Handler to be invoked when the leaf is clicked
+    #[darling(rename = "on_change")]
+    pub on_change_handler: Option<Path>,
+}
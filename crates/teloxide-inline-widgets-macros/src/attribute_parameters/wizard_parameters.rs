@@ -0,0 +1,18 @@
+use darling::FromField;
+use syn::Path;
+
+/// Arguments for the `#[wizard]` field attribute
+#[derive(Debug, FromField)]
+#[darling(attributes(wizard))]
+pub struct WizardParameters {
+    /// CallbackQuery data prefix of the navigation buttons
+    pub prefix: String,
+    /// CallbackQuery data for the progress indicator and empty cells
+    pub noop_data: Option<String>,
+    /// Validation hook of the steps, receives the user-defined widget and the
+    /// index of the current step and returns `Result<(), String>`
+    pub validate: Option<Path>,
+    /// Handler to be invoked when the last step is finished
+    #[darling(rename = "on_finish")]
+    pub on_finish_handler: Option<Path>,
+}
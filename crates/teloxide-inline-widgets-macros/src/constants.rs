@@ -1,4 +1,17 @@
 pub const NOOP_DATA: &str = "noop";
+/// Is appended to the widget prefix to get the default page prefix
+pub const PAGE_PREFIX: &str = "p_";
+
+pub mod button {
+    /// Are appended to the button data to get the `Yes/No` buttons data
+    pub const YES_SUFFIX: &str = "_y";
+    pub const NO_SUFFIX: &str = "_n";
+}
+
+pub mod dropdown {
+    /// Is appended to the widget prefix to get the default toggle data
+    pub const TOGGLE: &str = "t";
+}
 
 pub mod calendar {
     pub const DAY_PREFIX: &str = "d_";
@@ -8,9 +21,42 @@ pub mod calendar {
     pub const NEXT_YEAR: &str = "ny";
     pub const PREV_MONTH: &str = "pm";
     pub const NEXT_MONTH: &str = "nm";
+
+    pub const MONTH_PREFIX: &str = "m_";
+    pub const YEAR_PREFIX: &str = "y_";
+    pub const DAYS_VIEW: &str = "dv";
+    pub const MONTHS_VIEW: &str = "mv";
+    pub const YEARS_VIEW: &str = "yv";
+    pub const PREV_YEARS_PAGE: &str = "pyp";
+    pub const NEXT_YEARS_PAGE: &str = "nyp";
+}
+
+pub mod wizard {
+    pub const BACK: &str = "b";
+    pub const NEXT: &str = "n";
+    pub const FINISH: &str = "f";
+}
+
+pub mod date_time_picker {
+    pub const TIME_PICKER_PREFIX: &str = "t_";
+    pub const BACK: &str = "b";
 }
 
 pub const RADIO_LIST_TYPE: &str = "RadioList";
 pub const CHECKBOX_LIST_TYPE: &str = "CheckboxList";
 pub const BUTTON_TYPE: &str = "Button";
 pub const CALENDAR_TYPE: &str = "Calendar";
+pub const TIME_PICKER_TYPE: &str = "TimePicker";
+pub const DATE_TIME_PICKER_TYPE: &str = "DateTimePicker";
+pub const STEPPER_TYPE: &str = "Stepper";
+pub const TOGGLE_TYPE: &str = "Toggle";
+pub const KEYPAD_TYPE: &str = "Keypad";
+pub const RATING_TYPE: &str = "Rating";
+pub const TABS_TYPE: &str = "Tabs";
+pub const WIZARD_TYPE: &str = "Wizard";
+pub const MENU_TYPE: &str = "Menu";
+pub const DROPDOWN_TYPE: &str = "Dropdown";
+pub const SORTABLE_LIST_TYPE: &str = "SortableList";
+pub const TRANSFER_LIST_TYPE: &str = "TransferList";
+pub const TREE_VIEW_TYPE: &str = "TreeView";
+pub const TABLE_TYPE: &str = "Table";
@@ -7,11 +7,14 @@ use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Path, Type, TypePath
 
 use crate::{
     attribute_parameters::{
-        ButtonParameters, CalendarParameters, CheckboxListParameters, RadioListParameters,
+        ButtonParameters, CalendarParameters, CheckboxListParameters, DateTimePickerParameters,
+        DropdownParameters, KeypadParameters, MenuParameters, RadioListParameters,
+        RatingParameters, SortableListParameters, StepperParameters, TableParameters,
+        TabsParameters, TimePickerParameters, ToggleParameters, TransferListParameters,
+        TreeViewParameters, WizardParameters,
     },
     constants::*,
     inline_widget::impls::*,
-    schemes::CalendarSchemaTypes,
 };
 
 /// Arguments for the top-level `#[inline_widget]` struct attribute.
@@ -52,12 +55,69 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
         };
         let mut markups = vec![];
         let mut sizes = vec![];
+        // Types of the components which are retrieved through the `WidgetContainer`
+        let mut container_types = vec![];
+        // Toggles and confirmed buttons are retrieved through the widget itself, so
+        // there may be several of them
+        let mut has_self_container = false;
+        // Confirmation keyboards replacing the whole widget, and their sizes
+        let mut confirmations = vec![];
+        let mut confirmation_sizes = vec![];
+        // If the widget has the tab bar or the wizard, the other fields are displayed
+        // as tabs or steps, one page at a time
+        let field_type_names = match fields
+            .iter()
+            .map(|field| get_type_name(&field.ty))
+            .collect::<syn::Result<Vec<_>>>()
+        {
+            Ok(field_type_names) => field_type_names,
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
+        let mut pages_fields =
+            fields.iter().zip(&field_type_names).filter(|(_, field_type_name)| {
+                matches!(field_type_name.as_str(), TABS_TYPE | WIZARD_TYPE)
+            });
+        let pages_field = pages_fields.next();
+        // Only one field can split the widget into the pages
+        if let Some((field, _)) = pages_fields.next() {
+            return TokenStream::from(
+                syn::Error::new_spanned(
+                    field,
+                    "The widget can have only one field of the tabs or the wizard",
+                )
+                .to_compile_error(),
+            );
+        }
+        let is_wizard =
+            pages_field.is_some_and(|(_, field_type_name)| field_type_name.as_str() == WIZARD_TYPE);
+        let pages_field = pages_field.map(|(field, _)| field);
+        // The pages are always laid out vertically with the tab bar or the wizard
+        // navigation row
+        if let (Some(layout_orientation), Some(_)) = (&layout_orientation, pages_field) {
+            return TokenStream::from(
+                syn::Error::new_spanned(
+                    layout_orientation,
+                    "The layout orientation can't be set for the widget with the tabs or the \
+                     wizard",
+                )
+                .to_compile_error(),
+            );
+        }
+        let active_page = if is_wizard {
+            quote! {step}
+        } else {
+            quote! {active_tab}
+        };
+        let mut pages_markup = None;
 
-        for field in fields {
-            let field_ident =
-                field.ident.as_ref().expect("The user-defined widget field has to be named");
+        for (field, field_type_name) in fields.iter().zip(field_type_names) {
+            let Some(field_ident) = field.ident.as_ref() else {
+                return TokenStream::from(
+                    syn::Error::new_spanned(field, "The user-defined widget field has to be named")
+                        .to_compile_error(),
+                );
+            };
             let field_type = &field.ty;
-            let field_type_name = get_type_name(&field.ty);
 
             let component_parameters =
                 &ComponentParameters { struct_ident, field_ident, field_type };
@@ -66,17 +126,38 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                 self.#field_ident.size()
             });
 
+            // The schema of the page is active only when the page is active
+            let page_schema_impl = pages_field
+                .filter(|pages_field| pages_field.ident.as_ref() != Some(field_ident))
+                .map(|pages_field| {
+                    let (pages_type, page_i) = (&pages_field.ty, markups.len());
+                    let page_filter = quote! {
+                        dptree::filter(|mut widget: W| {
+                            <W as WidgetContainer<#pages_type>>::get_widget(&mut widget)
+                                .#active_page() == #page_i
+                        })
+                    };
+                    std::mem::replace(&mut schema_impl, page_filter)
+                });
+
             match field_type_name.as_str() {
                 RADIO_LIST_TYPE => {
                     let parameters = &match RadioListParameters::from_field(field) {
                         Ok(mut parameters) => {
+                            parameters.page_prefix = parameters
+                                .page_prefix
+                                .or(Some(format!("{}{PAGE_PREFIX}", parameters.prefix)));
                             parameters.noop_data =
                                 parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
                             parameters
                         }
                         Err(err) => return TokenStream::from(err.write_errors()),
                     };
-                    widget_container_impl(component_parameters, &mut widget_container_impls);
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
                     radio_list_component_impl(
                         parameters,
                         component_parameters,
@@ -87,6 +168,9 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                 CHECKBOX_LIST_TYPE => {
                     let parameters = &match CheckboxListParameters::from_field(field) {
                         Ok(mut parameters) => {
+                            parameters.page_prefix = parameters
+                                .page_prefix
+                                .or(Some(format!("{}{PAGE_PREFIX}", parameters.prefix)));
                             parameters.noop_data =
                                 parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
                             parameters
@@ -94,7 +178,11 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                         Err(err) => return TokenStream::from(err.write_errors()),
                     };
 
-                    widget_container_impl(component_parameters, &mut widget_container_impls);
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
                     checkbox_list_component_impl(
                         parameters,
                         component_parameters,
@@ -108,11 +196,14 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                         Err(err) => return TokenStream::from(err.write_errors()),
                     };
 
+                    has_self_container |= parameters.confirm.is_some();
                     button_component_impl(
                         parameters,
                         component_parameters,
                         &mut schema_impl,
                         &mut markups,
+                        &mut confirmations,
+                        &mut confirmation_sizes,
                     );
                 }
                 CALENDAR_TYPE => {
@@ -131,22 +222,328 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                                 parameters.prev_month.or(Some(calendar::PREV_MONTH.to_owned()));
                             parameters.next_month =
                                 parameters.next_month.or(Some(calendar::NEXT_MONTH.to_owned()));
+                            parameters.month_prefix =
+                                parameters.month_prefix.or(Some(calendar::MONTH_PREFIX.to_owned()));
+                            parameters.year_prefix =
+                                parameters.year_prefix.or(Some(calendar::YEAR_PREFIX.to_owned()));
+                            parameters.days_view =
+                                parameters.days_view.or(Some(calendar::DAYS_VIEW.to_owned()));
+                            parameters.months_view =
+                                parameters.months_view.or(Some(calendar::MONTHS_VIEW.to_owned()));
+                            parameters.years_view =
+                                parameters.years_view.or(Some(calendar::YEARS_VIEW.to_owned()));
+                            parameters.prev_years_page = parameters
+                                .prev_years_page
+                                .or(Some(calendar::PREV_YEARS_PAGE.to_owned()));
+                            parameters.next_years_page = parameters
+                                .next_years_page
+                                .or(Some(calendar::NEXT_YEARS_PAGE.to_owned()));
                             parameters.noop_data =
                                 parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
                             parameters
                         }
                         Err(err) => return TokenStream::from(err.write_errors()),
                     };
-                    widget_container_impl(component_parameters, &mut widget_container_impls);
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
                     calendar_component_impl(
                         parameters,
-                        &CalendarSchemaTypes {
-                            bot_ty: bot_ty.clone(),
-                            widget_ty: struct_ident.clone(),
-                            dialogue_ty: dialogue_ty.clone().expect(
-                                "There must be the dialogue type for the `Calendar` widget",
-                            ),
-                        },
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TIME_PICKER_TYPE => {
+                    let parameters = &match TimePickerParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    time_picker_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                DATE_TIME_PICKER_TYPE => {
+                    let parameters = &match DateTimePickerParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    date_time_picker_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                STEPPER_TYPE => {
+                    let parameters = &match StepperParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    stepper_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TOGGLE_TYPE => {
+                    let parameters = &match ToggleParameters::from_field(field) {
+                        Ok(parameters) => parameters,
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    has_self_container = true;
+                    toggle_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                KEYPAD_TYPE => {
+                    let parameters = &match KeypadParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    keypad_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                RATING_TYPE => {
+                    let parameters = &match RatingParameters::from_field(field) {
+                        Ok(parameters) => parameters,
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    rating_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TABS_TYPE => {
+                    let parameters = &match TabsParameters::from_field(field) {
+                        Ok(parameters) => parameters,
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    // The tab bar is displayed separately from the tabs
+                    sizes.pop();
+                    pages_markup = Some(tabs_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        fields.iter().count() - 1,
+                    ));
+                }
+                WIZARD_TYPE => {
+                    let parameters = &match WizardParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    // The navigation row is displayed separately from the steps
+                    sizes.pop();
+                    has_self_container = true;
+                    pages_markup = Some(wizard_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        fields.iter().count() - 1,
+                    ));
+                }
+                MENU_TYPE => {
+                    let parameters = &match MenuParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    menu_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                DROPDOWN_TYPE => {
+                    let parameters = &match DropdownParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.page_prefix = parameters
+                                .page_prefix
+                                .or(Some(format!("{}{PAGE_PREFIX}", parameters.prefix)));
+                            parameters.toggle_data = parameters.toggle_data.or(Some(format!(
+                                "{}{}",
+                                parameters.prefix,
+                                dropdown::TOGGLE
+                            )));
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    dropdown_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                SORTABLE_LIST_TYPE => {
+                    let parameters = &match SortableListParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    sortable_list_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TRANSFER_LIST_TYPE => {
+                    let parameters = &match TransferListParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.page_prefix = parameters
+                                .page_prefix
+                                .or(Some(format!("{}{PAGE_PREFIX}", parameters.prefix)));
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    transfer_list_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TREE_VIEW_TYPE => {
+                    let parameters = &match TreeViewParameters::from_field(field) {
+                        Ok(parameters) => parameters,
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    tree_view_component_impl(
+                        parameters,
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
+                }
+                TABLE_TYPE => {
+                    let parameters = &match TableParameters::from_field(field) {
+                        Ok(mut parameters) => {
+                            parameters.page_prefix = parameters
+                                .page_prefix
+                                .or(Some(format!("{}{PAGE_PREFIX}", parameters.prefix)));
+                            parameters.noop_data =
+                                parameters.noop_data.or(Some(NOOP_DATA.to_owned()));
+                            parameters
+                        }
+                        Err(err) => return TokenStream::from(err.write_errors()),
+                    };
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    table_component_impl(
+                        parameters,
                         component_parameters,
                         &mut schema_impl,
                         &mut markups,
@@ -154,15 +551,59 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
                 }
                 // User-defined types
                 _ => {
-                    unimplemented!()
+                    widget_container_impl(
+                        component_parameters,
+                        &mut widget_container_impls,
+                        &mut container_types,
+                    );
+                    nested_widget_component_impl(
+                        component_parameters,
+                        &mut schema_impl,
+                        &mut markups,
+                    );
                 }
             }
+
+            if let Some(mut page_schema_impl) = page_schema_impl {
+                page_schema_impl.extend(quote! { .branch(#schema_impl) });
+                schema_impl = page_schema_impl;
+            }
         }
 
-        let layout_orientation = layout_orientation
+        let mut layout_orientation = layout_orientation
             .map(|lo| quote! {#lo})
             .unwrap_or(quote! {LayoutOrientation::Vertical});
 
+        if let (Some(pages_field), Some(pages_markup)) = (pages_field, pages_markup) {
+            let pages_ident = &pages_field.ident;
+            let page_indices = 0..markups.len();
+            let empty_page = quote! {
+                _ => (teloxide::types::InlineKeyboardMarkup::default(), Size::new(0, 0))
+            };
+            let page_markup = quote! {
+                match self.#pages_ident.#active_page() {
+                    #(#page_indices => #markups,)*
+                    #empty_page
+                }
+            };
+            let page_indices = 0..sizes.len();
+            let page_size = quote! {
+                match self.#pages_ident.#active_page() {
+                    #(#page_indices => #sizes,)*
+                    _ => Size::new(0, 0)
+                }
+            };
+            let pages_size = quote! { self.#pages_ident.size() };
+            // The tab bar is always above the active tab, and the wizard navigation row is
+            // beneath the current step
+            (markups, sizes) = if is_wizard {
+                (vec![page_markup, pages_markup], vec![page_size, pages_size])
+            } else {
+                (vec![pages_markup, page_markup], vec![pages_size, page_size])
+            };
+            layout_orientation = quote! {LayoutOrientation::Vertical};
+        }
+
         let first_markup = &markups[0];
         let inline_keyboard_markup_impl = if fields.iter().count() == 1 {
             quote! {
@@ -181,33 +622,65 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
             .map(|state| quote! {dialogue.update(#state(self)).await?; Ok(())})
             .unwrap_or(quote! {unimplemented!()});
         let dialogue_ty = dialogue_ty.map(|ty| quote! {#ty}).unwrap_or(quote! {()});
+        let self_container_impl = has_self_container.then(|| {
+            quote! {
+                impl WidgetContainer<#struct_ident> for #struct_ident {
+                    fn get_widget(&mut self) -> &mut Self {
+                        self
+                    }
+                }
+            }
+        });
+        let self_container_bound =
+            has_self_container.then(|| quote! {+ WidgetContainer<#struct_ident>});
 
         quote! {
             #widget_container_impls
+            #self_container_impl
 
             impl GetSize for #struct_ident {
                 fn size(&self) -> Size {
+                    #(#confirmation_sizes)*
                     let (rows, columns) = [#(#sizes),*].iter().fold((0, 0), |required_size, size| {
                         let Size { rows, columns } = size;
                         match #layout_orientation {
-                            Horizontal => (required_size.0.max(*rows), required_size.1 + columns),
-                            Vertical => (required_size.0 + rows, required_size.1.max(*columns)),
+                            LayoutOrientation::Horizontal => (required_size.0.max(*rows), required_size.1 + columns),
+                            LayoutOrientation::Vertical => (required_size.0 + rows, required_size.1.max(*columns)),
                         }
                     });
                     Size { rows, columns }
                 }
             }
 
+            impl #struct_ident {
+                /// Returns the [`dptree`]-handler schema for the widget, which is nested
+                /// into the `W` widget (or is the `W` widget itself)
+                pub fn schema_for<W>() -> teloxide::dispatching::UpdateHandler<#err_ty>
+                where
+                    W: 'static
+                        + Clone
+                        + Send
+                        + Sync
+                        + InlineWidget<Bot = #bot_ty, Err = #err_ty>
+                        #(+ WidgetContainer<#container_types>)*
+                        #self_container_bound,
+                    W::Dialogue: 'static + Clone + Send + Sync,
+                {
+                    #schema_impl
+                }
+            }
+
             impl InlineWidget for #struct_ident {
                 type Bot = #bot_ty;
                 type Err = #err_ty;
                 type Dialogue = #dialogue_ty;
 
                 fn schema() -> teloxide::dispatching::UpdateHandler<Self::Err> {
-                    #schema_impl
+                    Self::schema_for::<Self>()
                 }
 
                 fn inline_keyboard_markup(&self, styles: &WidgetStyles) -> teloxide::types::InlineKeyboardMarkup {
+                    #(#confirmations)*
                     #inline_keyboard_markup_impl
                 }
 
@@ -225,9 +698,22 @@ pub(crate) fn inline_widget_impl(input: TokenStream) -> TokenStream {
     }
 }
 
-fn get_type_name(ty: &Type) -> String {
+/// Returns the name of the field type, e.g. `RadioList` for the
+/// `teloxide_inline_widgets::RadioList<T>`
+///
+/// Only the paths can be used as the widget types, so the error is returned for
+/// the references, tuples, arrays etc.
+fn get_type_name(ty: &Type) -> syn::Result<String> {
     match ty {
-        syn::Type::Path(TypePath { path, .. }) => path.segments.last().unwrap().ident.to_string(),
-        _ => panic!("Unable to get the type name for: {ty:#?}"),
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .ok_or_else(|| syn::Error::new_spanned(ty, "Unable to get the widget type name")),
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "The widget field type has to be either a widget (e.g. `RadioList<T>`) or a \
+             user-defined widget type deriving `InlineWidget`",
+        )),
     }
 }
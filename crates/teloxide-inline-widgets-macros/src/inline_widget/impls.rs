@@ -1,11 +1,8 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{Ident, Type};
+use quote::{quote, ToTokens};
+use syn::{Ident, Path, Type};
 
-use crate::{
-    attribute_parameters::*,
-    schemes::{button_schema, calendar_schema, CalendarSchemaTypes},
-};
+use crate::{attribute_parameters::*, schemes::button_schema};
 
 pub struct ComponentParameters<'a> {
     /// User-defined widget identifier
@@ -16,10 +13,28 @@ pub struct ComponentParameters<'a> {
     pub field_type: &'a Type,
 }
 
-pub fn widget_container_impl(
-    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
+/// Wraps the optional user-defined handler into the [`dptree`]-endpoint
+fn optional_handler(handler: &Option<Path>) -> TokenStream2 {
+    match handler {
+        Some(handler) => quote! { Some(dptree::endpoint(#handler)) },
+        None => quote! { None },
+    }
+}
+
+/// Converts the optional attribute value into the [`Option`] expression
+fn optional_value(value: &Option<impl ToTokens>) -> TokenStream2 {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+pub fn widget_container_impl<'a>(
+    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters<'a>,
     widget_container_impls: &mut TokenStream2,
+    container_types: &mut Vec<&'a Type>,
 ) {
+    container_types.push(field_type);
     widget_container_impls.extend(quote! {
         impl WidgetContainer<#field_type> for #struct_ident {
             fn get_widget(&mut self) -> &mut #field_type {
@@ -30,19 +45,21 @@ pub fn widget_container_impl(
 }
 
 pub fn radio_list_component_impl(
-    RadioListParameters { prefix, noop_data }: &RadioListParameters,
-    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
+    RadioListParameters { prefix, page_prefix, noop_data, on_change_handler }: &RadioListParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
     schema_impl: &mut TokenStream2,
     markups: &mut Vec<TokenStream2>,
 ) {
     let radio_list_schema_parameters = quote! {
         RadioListSchemaParameters {
             prefix: #prefix,
+            page_prefix: #page_prefix,
             noop_data: #noop_data
         }
     };
+    let on_change_handler = optional_handler(on_change_handler);
     schema_impl.extend(quote! {
-        .branch(<#field_type>::schema::<#struct_ident>(&#radio_list_schema_parameters))
+        .branch(<#field_type>::schema::<W>(&#radio_list_schema_parameters, #on_change_handler))
     });
     markups.push(quote! {
         (
@@ -53,19 +70,24 @@ pub fn radio_list_component_impl(
 }
 
 pub fn checkbox_list_component_impl(
-    CheckboxListParameters { prefix, noop_data }: &CheckboxListParameters,
-    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
+    CheckboxListParameters { prefix, page_prefix, noop_data, min, max, on_toggle_handler }: &CheckboxListParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
     schema_impl: &mut TokenStream2,
     markups: &mut Vec<TokenStream2>,
 ) {
+    let (min, max) = (optional_value(min), optional_value(max));
     let checkbox_list_schema_parameters = quote! {
         CheckboxListSchemaParameters {
             prefix: #prefix,
-            noop_data: #noop_data
+            page_prefix: #page_prefix,
+            noop_data: #noop_data,
+            min_selected: #min,
+            max_selected: #max
         }
     };
+    let on_toggle_handler = optional_handler(on_toggle_handler);
     schema_impl.extend(quote! {
-        .branch(<#field_type>::schema::<#struct_ident>(&#checkbox_list_schema_parameters))
+        .branch(<#field_type>::schema::<W>(&#checkbox_list_schema_parameters, #on_toggle_handler))
     });
     markups.push(quote! {
         (
@@ -75,12 +97,408 @@ pub fn checkbox_list_component_impl(
     });
 }
 
+pub fn time_picker_component_impl(
+    TimePickerParameters { prefix, noop_data, on_confirm_handler }: &TimePickerParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let time_picker_schema_parameters = quote! {
+        TimePickerSchemaParameters {
+            prefix: #prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_confirm_handler = optional_handler(on_confirm_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#time_picker_schema_parameters, #on_confirm_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#time_picker_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn stepper_component_impl(
+    StepperParameters { prefix, noop_data, on_change_handler }: &StepperParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let stepper_schema_parameters = quote! {
+        StepperSchemaParameters {
+            prefix: #prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#stepper_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#stepper_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn toggle_component_impl(
+    ToggleParameters { data, on_change_handler }: &ToggleParameters,
+    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let toggle_schema_parameters = quote! {
+        ToggleSchemaParameters {
+            data: #data
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W, #struct_ident>(
+            &#toggle_schema_parameters,
+            |widget| &mut widget.#field_ident,
+            #on_change_handler
+        ))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#toggle_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn date_time_picker_component_impl(
+    DateTimePickerParameters { prefix, noop_data, on_confirm_handler, is_day_disabled }: &DateTimePickerParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    use crate::constants::{calendar::*, date_time_picker::*};
+
+    let is_day_disabled = optional_value(is_day_disabled);
+    // The data of the nested widgets is prefixed with the widget prefix
+    let data = |data: &str| format!("{prefix}{data}");
+    let (day_prefix, weekday_prefix) = (data(DAY_PREFIX), data(WEEKDAY_PREFIX));
+    let (prev_year, next_year) = (data(PREV_YEAR), data(NEXT_YEAR));
+    let (prev_month, next_month) = (data(PREV_MONTH), data(NEXT_MONTH));
+    let (month_prefix, year_prefix) = (data(MONTH_PREFIX), data(YEAR_PREFIX));
+    let (days_view, months_view, years_view) =
+        (data(DAYS_VIEW), data(MONTHS_VIEW), data(YEARS_VIEW));
+    let (prev_years_page, next_years_page) = (data(PREV_YEARS_PAGE), data(NEXT_YEARS_PAGE));
+    let (time_picker_prefix, back_data) = (data(TIME_PICKER_PREFIX), data(BACK));
+    let date_time_picker_schema_parameters = quote! {
+        DateTimePickerSchemaParameters {
+            calendar: CalendarSchemaParameters {
+                day_prefix: #day_prefix,
+                weekday_prefix: #weekday_prefix,
+                previous_year_data: #prev_year,
+                next_year_data: #next_year,
+                previous_month_data: #prev_month,
+                next_month_data: #next_month,
+                noop_data: #noop_data,
+                month_prefix: #month_prefix,
+                year_prefix: #year_prefix,
+                days_view_data: #days_view,
+                months_view_data: #months_view,
+                years_view_data: #years_view,
+                previous_years_page_data: #prev_years_page,
+                next_years_page_data: #next_years_page,
+                is_day_disabled: #is_day_disabled,
+            },
+            time_picker: TimePickerSchemaParameters {
+                prefix: #time_picker_prefix,
+                noop_data: #noop_data
+            },
+            back_data: #back_data
+        }
+    };
+    let on_confirm_handler = optional_handler(on_confirm_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#date_time_picker_schema_parameters, #on_confirm_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#date_time_picker_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn keypad_component_impl(
+    KeypadParameters { prefix, noop_data, on_confirm_handler }: &KeypadParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let keypad_schema_parameters = quote! {
+        KeypadSchemaParameters {
+            prefix: #prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_confirm_handler = optional_handler(on_confirm_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#keypad_schema_parameters, #on_confirm_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#keypad_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn rating_component_impl(
+    RatingParameters { prefix, clearable, on_change_handler }: &RatingParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let rating_schema_parameters = quote! {
+        RatingSchemaParameters {
+            prefix: #prefix,
+            clearable: #clearable
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#rating_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#rating_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+/// Returns the markup of the tab bar, which is displayed above the active tab
+pub fn tabs_component_impl(
+    TabsParameters { prefix }: &TabsParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    tabs: usize,
+) -> TokenStream2 {
+    let tabs_schema_parameters = quote! {
+        TabsSchemaParameters {
+            prefix: #prefix,
+            tabs: #tabs
+        }
+    };
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#tabs_schema_parameters))
+    });
+    quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#tabs_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    }
+}
+
+pub fn wizard_component_impl(
+    WizardParameters { prefix, noop_data, validate, on_finish_handler }: &WizardParameters,
+    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    steps: usize,
+) -> TokenStream2 {
+    use crate::constants::wizard::*;
+
+    let (back_data, next_data, finish_data) =
+        (format!("{prefix}{BACK}"), format!("{prefix}{NEXT}"), format!("{prefix}{FINISH}"));
+    let wizard_schema_parameters = quote! {
+        WizardSchemaParameters {
+            back_data: #back_data,
+            next_data: #next_data,
+            finish_data: #finish_data,
+            noop_data: #noop_data,
+            steps: #steps
+        }
+    };
+    let validate =
+        validate.as_ref().map(|validate| quote! {Some(#validate)}).unwrap_or(quote! {None});
+    let on_finish_handler = optional_handler(on_finish_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W, #struct_ident>(
+            &#wizard_schema_parameters,
+            #validate,
+            #on_finish_handler
+        ))
+    });
+    quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#wizard_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    }
+}
+
+pub fn menu_component_impl(
+    MenuParameters { prefix, noop_data, on_select_handler }: &MenuParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let menu_schema_parameters = quote! {
+        MenuSchemaParameters {
+            prefix: #prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_select_handler = optional_handler(on_select_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#menu_schema_parameters, #on_select_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#menu_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn dropdown_component_impl(
+    DropdownParameters { prefix, page_prefix, toggle_data, noop_data, on_change_handler }: &DropdownParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let dropdown_schema_parameters = quote! {
+        DropdownSchemaParameters {
+            radio_list: RadioListSchemaParameters {
+                prefix: #prefix,
+                page_prefix: #page_prefix,
+                noop_data: #noop_data
+            },
+            toggle_data: #toggle_data
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#dropdown_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#dropdown_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn sortable_list_component_impl(
+    SortableListParameters { prefix, noop_data, on_change_handler }: &SortableListParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let sortable_list_schema_parameters = quote! {
+        SortableListSchemaParameters {
+            prefix: #prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#sortable_list_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#sortable_list_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn transfer_list_component_impl(
+    TransferListParameters { prefix, page_prefix, noop_data, on_change_handler }: &TransferListParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let transfer_list_schema_parameters = quote! {
+        TransferListSchemaParameters {
+            prefix: #prefix,
+            page_prefix: #page_prefix,
+            noop_data: #noop_data
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#transfer_list_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#transfer_list_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn tree_view_component_impl(
+    TreeViewParameters { prefix, on_change_handler }: &TreeViewParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let tree_view_schema_parameters = quote! {
+        TreeViewSchemaParameters {
+            prefix: #prefix
+        }
+    };
+    let on_change_handler = optional_handler(on_change_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#tree_view_schema_parameters, #on_change_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#tree_view_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
+pub fn table_component_impl(
+    TableParameters { prefix, page_prefix, noop_data, on_click_handler }: &TableParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    let is_clickable = on_click_handler.is_some();
+    let table_schema_parameters = quote! {
+        TableSchemaParameters {
+            prefix: #prefix,
+            page_prefix: #page_prefix,
+            noop_data: #noop_data,
+            is_clickable: #is_clickable
+        }
+    };
+    let on_click_handler = optional_handler(on_click_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(&#table_schema_parameters, #on_click_handler))
+    });
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&#table_schema_parameters, &styles),
+            self.#field_ident.size()
+        )
+    });
+}
+
 pub fn button_component_impl(
     parameters: &ButtonParameters,
-    ComponentParameters { field_ident, .. }: &ComponentParameters,
+    ComponentParameters { struct_ident, field_ident, field_type }: &ComponentParameters,
     schema_impl: &mut TokenStream2,
     markups: &mut Vec<TokenStream2>,
+    confirmations: &mut Vec<TokenStream2>,
+    confirmation_sizes: &mut Vec<TokenStream2>,
 ) {
+    use crate::constants::{button::*, NOOP_DATA};
+
     let data = &parameters.data;
     markups.push(quote! {
         (
@@ -88,13 +506,47 @@ pub fn button_component_impl(
             self.#field_ident.size()
         )
     });
-    schema_impl.extend(button_schema(parameters));
+    let Some(question) = &parameters.confirm else {
+        schema_impl.extend(button_schema(parameters));
+        return;
+    };
+
+    let (yes_data, no_data) = (format!("{data}{YES_SUFFIX}"), format!("{data}{NO_SUFFIX}"));
+    let button_confirm_parameters = quote! {
+        ButtonConfirmParameters {
+            data: #data,
+            question: #question,
+            yes_data: #yes_data,
+            no_data: #no_data,
+            noop_data: #NOOP_DATA
+        }
+    };
+    let click_handler = &parameters.click_handler;
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::confirm_schema::<W, #struct_ident>(
+            &#button_confirm_parameters,
+            |widget| &mut widget.#field_ident,
+            dptree::endpoint(#click_handler)
+        ))
+    });
+    confirmations.push(quote! {
+        if self.#field_ident.is_confirming() {
+            return self.#field_ident.confirmation_inline_keyboard_markup(
+                &#button_confirm_parameters,
+                &styles
+            );
+        }
+    });
+    confirmation_sizes.push(quote! {
+        if self.#field_ident.is_confirming() {
+            return self.#field_ident.confirmation_size();
+        }
+    });
 }
 
 pub fn calendar_component_impl(
     parameters: &CalendarParameters,
-    schema_types: &CalendarSchemaTypes,
-    ComponentParameters { field_ident, .. }: &ComponentParameters,
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
     schema_impl: &mut TokenStream2,
     markups: &mut Vec<TokenStream2>,
 ) {
@@ -106,8 +558,19 @@ pub fn calendar_component_impl(
         prev_month,
         next_month,
         noop_data,
-        ..
+        month_prefix,
+        year_prefix,
+        days_view,
+        months_view,
+        years_view,
+        prev_years_page,
+        next_years_page,
+        is_day_disabled,
+        day_click_handler,
+        range_selected_handler,
+        weekday_click_handler,
     } = &parameters;
+    let is_day_disabled = optional_value(is_day_disabled);
 
     let calendar_schema_parameters = quote! {
         CalendarSchemaParameters {
@@ -118,6 +581,14 @@ pub fn calendar_component_impl(
             previous_month_data: #prev_month,
             next_month_data: #next_month,
             noop_data: #noop_data,
+            month_prefix: #month_prefix,
+            year_prefix: #year_prefix,
+            days_view_data: #days_view,
+            months_view_data: #months_view,
+            years_view_data: #years_view,
+            previous_years_page_data: #prev_years_page,
+            next_years_page_data: #next_years_page,
+            is_day_disabled: #is_day_disabled,
         }
     };
 
@@ -127,5 +598,35 @@ pub fn calendar_component_impl(
             self.#field_ident.size()
         )
     });
-    schema_impl.extend(calendar_schema(schema_types, parameters));
+    let day_click_handler = optional_handler(day_click_handler);
+    let range_selected_handler = optional_handler(range_selected_handler);
+    let weekday_click_handler = optional_handler(weekday_click_handler);
+    schema_impl.extend(quote! {
+        .branch(<#field_type>::schema::<W>(
+            &#calendar_schema_parameters,
+            #day_click_handler,
+            #range_selected_handler,
+            #weekday_click_handler
+        ))
+    });
+}
+
+pub fn nested_widget_component_impl(
+    ComponentParameters { field_ident, field_type, .. }: &ComponentParameters,
+    schema_impl: &mut TokenStream2,
+    markups: &mut Vec<TokenStream2>,
+) {
+    markups.push(quote! {
+        (
+            self.#field_ident.inline_keyboard_markup(&styles),
+            self.#field_ident.size()
+        )
+    });
+    schema_impl.extend(quote! {
+        .branch(
+            dptree::entry()
+            .map(|widget: W| NestedWidget::<W, #field_type>::new(widget))
+            .branch(<#field_type>::schema_for::<NestedWidget<W, #field_type>>())
+        )
+    });
 }
@@ -7,7 +7,27 @@ use proc_macro::TokenStream;
 
 #[proc_macro_derive(
     InlineWidget,
-    attributes(inline_widget, radio_list, checkbox_list, button, calendar)
+    attributes(
+        inline_widget,
+        radio_list,
+        checkbox_list,
+        button,
+        calendar,
+        time_picker,
+        date_time_picker,
+        stepper,
+        toggle,
+        keypad,
+        rating,
+        tabs,
+        wizard,
+        menu,
+        dropdown,
+        sortable_list,
+        transfer_list,
+        tree_view,
+        table
+    )
 )]
 pub fn derive_inline_widget(input: TokenStream) -> TokenStream {
     inline_widget::inline_widget_impl(input)
@@ -1,7 +1,3 @@
 mod button;
-mod calendar;
 
-pub use self::{
-    button::button_schema,
-    calendar::{calendar_schema, CalendarSchemaTypes},
-};
+pub use self::button::button_schema;
@@ -4,7 +4,9 @@ use quote::quote;
 use crate::attribute_parameters::ButtonParameters;
 
 /// Handler schema for the [`Button`] widget
-pub fn button_schema(ButtonParameters { data, click_handler }: &ButtonParameters) -> TokenStream2 {
+pub fn button_schema(
+    ButtonParameters { data, click_handler, .. }: &ButtonParameters,
+) -> TokenStream2 {
     quote! {
         .branch(
             dptree::entry()
@@ -1,7 +1,8 @@
-//! This example demonstrates how to use the `Calendar` widget.
+//! This example demonstrates how to use the `Calendar` widget as a date picker.
+use chrono::{Datelike, Local};
 use serde::{Deserialize, Serialize};
 use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
-use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Calendar};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, Calendar};
 
 type Bot = teloxide::Bot;
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
@@ -17,12 +18,16 @@ enum State {
     ChoosingDate(ChooseDateWidget),
 }
 
-#[derive(Debug, Clone, Default, Deserialize, Serialize, InlineWidget)]
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
 #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
 #[inline_widget(state = State::ChoosingDate)]
 struct ChooseDateWidget {
-    #[calendar(day_click = show_clicked_day, weekday_click = show_clicked_weekday)]
+    // The clicked day is stored as the selected date, so there is no need in the
+    // `day_click` handler
+    #[calendar(weekday_click = show_clicked_weekday, is_day_disabled = is_weekend)]
     pub calendar: Calendar,
+    #[button(data = "confirm", click = confirm_date)]
+    pub confirm_button: Button,
 }
 
 #[tokio::main]
@@ -60,7 +65,10 @@ async fn send_widget(
     message: Message,
     widget_styles: WidgetStyles,
 ) -> HandlerResult {
-    let widget = ChooseDateWidget::default();
+    let mut widget =
+        ChooseDateWidget { calendar: Calendar::new(), confirm_button: Button::new("Confirm") };
+    // The past days can't be chosen
+    widget.calendar.set_min_date(Some(Local::now().date_naive()));
 
     bot.send_message(message.chat.id, "Choose a date:")
         .reply_markup(widget.inline_keyboard_markup(&widget_styles))
@@ -71,15 +79,26 @@ async fn send_widget(
     Ok(())
 }
 
-async fn show_clicked_day(bot: Bot, cq: CallbackQuery, clicked_day: NaiveDate) -> HandlerResult {
+async fn confirm_date(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: ChooseDateWidget,
+) -> HandlerResult {
+    let Some(date) = widget.calendar.selected_date() else {
+        bot.answer_callback_query(cq.id).text("Choose a date first").await?;
+        return Ok(());
+    };
     bot.answer_callback_query(cq.id).await?;
 
     bot.send_message(
         cq.message.unwrap().chat.id,
-        format!("You've clicked: {}", clicked_day.format("%Y-%m-%d")),
+        format!("You've chosen: {}", date.format("%Y-%m-%d")),
     )
     .await?;
 
+    dialogue.update(State::Idle).await?;
+
     Ok(())
 }
 
@@ -95,3 +114,7 @@ async fn show_clicked_weekday(
 
     Ok(())
 }
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
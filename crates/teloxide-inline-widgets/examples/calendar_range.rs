@@ -0,0 +1,94 @@
+//! This example demonstrates how to select the range of dates with the
+//! `Calendar` widget.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Calendar};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    ChoosingDates(ChooseDatesWidget),
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::ChoosingDates)]
+struct ChooseDatesWidget {
+    #[calendar(range_selected = book_dates)]
+    pub calendar: Calendar,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"calendar_range\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(
+                    dptree::case![State::ChoosingDates(_w)].branch(ChooseDatesWidget::schema()),
+                ),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let mut widget = ChooseDatesWidget::default();
+    widget.calendar.set_selection_mode(CalendarSelectionMode::Range);
+
+    bot.send_message(message.chat.id, "Choose the check-in and the check-out dates:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::ChoosingDates(widget)).await?;
+
+    Ok(())
+}
+
+async fn book_dates(
+    bot: Bot,
+    cq: CallbackQuery,
+    (check_in, check_out): (NaiveDate, NaiveDate),
+) -> HandlerResult {
+    bot.send_message(
+        cq.message.unwrap().chat.id,
+        format!(
+            "You've booked the room from {} to {}",
+            check_in.format("%Y-%m-%d"),
+            check_out.format("%Y-%m-%d")
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -24,7 +24,7 @@ enum State {
 #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
 #[inline_widget(state = State::ChoosingVariants)]
 struct ChooseVariantsWidget {
-    #[checkbox_list(prefix = "v_")]
+    #[checkbox_list(prefix = "v_", max = 2)]
     pub variants: CheckboxList<Variant>,
 }
 
@@ -0,0 +1,96 @@
+//! This example demonstrates how to ask for the confirmation before the
+//! `Button` click is handled.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    ManagingAccount(AccountWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::ManagingAccount, layout_orientation = LayoutOrientation::Horizontal)]
+struct AccountWidget {
+    #[button(data = "logout", click = log_out)]
+    pub logout_button: Button,
+    #[button(data = "delete", click = delete_account, confirm = "Delete the account forever?")]
+    pub delete_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"confirmation\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::ManagingAccount(_w)].branch(AccountWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = AccountWidget {
+        logout_button: Button::new("Log out"),
+        delete_button: Button::new("Delete account"),
+    };
+
+    bot.send_message(message.chat.id, "Account:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::ManagingAccount(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_out(bot: Bot, dialogue: Dialogue, cq: CallbackQuery) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+    bot.send_message(cq.message.unwrap().chat.id, "You've logged out").await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
+
+async fn delete_account(bot: Bot, dialogue: Dialogue, cq: CallbackQuery) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+    bot.send_message(cq.message.unwrap().chat.id, "The account is deleted").await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! This example demonstrates how to use the `DateTimePicker` widget.
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, DateTimePicker};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    SchedulingReminder(ScheduleReminderWidget),
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::SchedulingReminder)]
+struct ScheduleReminderWidget {
+    #[date_time_picker(prefix = "dt_", on_confirm = schedule_reminder)]
+    pub date_time_picker: DateTimePicker,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"date_time_picker\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(
+                    dptree::case![State::SchedulingReminder(_w)]
+                        .branch(ScheduleReminderWidget::schema()),
+                ),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let mut widget = ScheduleReminderWidget::default();
+    widget.date_time_picker.calendar_mut().set_min_date(Some(Local::now().date_naive()));
+    widget.date_time_picker.time_picker_mut().set_minute_step(15);
+    // Normally the time zone of each user should arrive from some database or other
+    // persistent storage or so
+    widget.date_time_picker.set_utc_offset(Some(*Local::now().offset()));
+
+    bot.send_message(message.chat.id, "When should I remind you?")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::SchedulingReminder(widget)).await?;
+
+    Ok(())
+}
+
+async fn schedule_reminder(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    date_time: DateTime<FixedOffset>,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    bot.send_message(
+        cq.message.unwrap().chat.id,
+        format!("The reminder is scheduled at {}", date_time.to_rfc2822()),
+    )
+    .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
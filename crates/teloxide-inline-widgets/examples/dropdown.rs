@@ -0,0 +1,101 @@
+//! This example demonstrates how to use the `Dropdown` widget to save the
+//! space of the keyboard.
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Dropdown};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Ordering(OrderWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Ordering)]
+struct OrderWidget {
+    #[dropdown(prefix = "s_", on_change = log_size)]
+    pub size: Dropdown<ShirtSize>,
+    #[dropdown(prefix = "c_")]
+    pub color: Dropdown<Color>,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum ShirtSize {
+    S,
+    M,
+    L,
+    XL,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum Color {
+    #[display(fmt = "⚫ Black")]
+    Black,
+    #[display(fmt = "⚪ White")]
+    White,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"dropdown\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Ordering(_w)].branch(OrderWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = OrderWidget {
+        size: Dropdown::from(vec![ShirtSize::S, ShirtSize::M, ShirtSize::L, ShirtSize::XL]),
+        color: Dropdown::from(vec![Color::Black, Color::White]),
+    };
+
+    bot.send_message(message.chat.id, "Choose the shirt:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Ordering(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_size(widget: OrderWidget) -> HandlerResult {
+    log::info!("The size is changed to {:?}", widget.size.active_item());
+
+    Ok(())
+}
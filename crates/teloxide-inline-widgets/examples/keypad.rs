@@ -0,0 +1,96 @@
+//! This example demonstrates how to use the `Keypad` widget to enter a one-time
+//! code.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Keypad};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+const CODE_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    EnteringCode(EnterCodeWidget),
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::EnteringCode)]
+struct EnterCodeWidget {
+    #[keypad(prefix = "k_", on_confirm = check_code)]
+    pub keypad: Keypad,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"keypad\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::EnteringCode(_w)].branch(EnterCodeWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let mut widget = EnterCodeWidget { keypad: Keypad::new(Some(CODE_LENGTH)) };
+    widget.keypad.set_masked(true);
+
+    bot.send_message(message.chat.id, "Enter the code (it's 1234):")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::EnteringCode(widget)).await?;
+
+    Ok(())
+}
+
+async fn check_code(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    KeypadInput(code): KeypadInput,
+) -> HandlerResult {
+    if code.len() < CODE_LENGTH {
+        bot.answer_callback_query(cq.id).text("The code is too short").await?;
+        return Ok(());
+    }
+    bot.answer_callback_query(cq.id).await?;
+
+    let text = if code == "1234" { "The code is correct" } else { "The code is wrong" };
+    bot.send_message(cq.message.unwrap().chat.id, text).await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! This example demonstrates how to use the `Menu` widget to navigate through
+//! the nested settings.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Menu};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Navigating(SettingsWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Navigating)]
+struct SettingsWidget {
+    #[menu(prefix = "m_", on_select = apply_action)]
+    pub menu: Menu<Action>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+enum Action {
+    SetLanguage(String),
+    MuteNotifications,
+    UnmuteNotifications,
+    Help,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"menu\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Navigating(_w)].branch(SettingsWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = SettingsWidget {
+        menu: Menu::new([
+            MenuNode::submenu(
+                "Settings",
+                [
+                    MenuNode::submenu(
+                        "Language",
+                        [
+                            MenuNode::item("English", Action::SetLanguage("en".to_owned())),
+                            MenuNode::item("Deutsch", Action::SetLanguage("de".to_owned())),
+                        ],
+                    ),
+                    MenuNode::submenu(
+                        "Notifications",
+                        [
+                            MenuNode::item("Mute", Action::MuteNotifications),
+                            MenuNode::item("Unmute", Action::UnmuteNotifications),
+                        ],
+                    ),
+                ],
+            ),
+            MenuNode::item("Help", Action::Help),
+        ]),
+    };
+
+    bot.send_message(message.chat.id, "Menu:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Navigating(widget)).await?;
+
+    Ok(())
+}
+
+async fn apply_action(bot: Bot, cq: CallbackQuery, action: Action) -> HandlerResult {
+    let text = match action {
+        Action::SetLanguage(language) => format!("The language is set to \"{language}\""),
+        Action::MuteNotifications => "The notifications are muted".to_owned(),
+        Action::UnmuteNotifications => "The notifications are unmuted".to_owned(),
+        Action::Help => "Choose the setting to change".to_owned(),
+    };
+    bot.answer_callback_query(cq.id).text(text).await?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! This example demonstrates how to nest the user-defined widgets into each
+//! other.
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, CheckboxList, RadioList};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+// The widget is stored within the state as is, see the `state` parameter of the
+// `#[inline_widget]` attribute
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Checkout(CheckoutForm),
+}
+
+/// The widget that is nested into the [`CheckoutForm`], so it needs
+/// neither the dialogue type nor the state
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot)]
+struct AddressForm {
+    #[radio_list(prefix = "c_")]
+    pub city: RadioList<City>,
+    #[checkbox_list(prefix = "o_")]
+    pub options: CheckboxList<DeliveryOption>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Checkout)]
+struct CheckoutForm {
+    pub address: AddressForm,
+    #[radio_list(prefix = "p_")]
+    pub payment: RadioList<Payment>,
+    #[button(data = "checkout", click = process_checkout)]
+    pub checkout_button: Button,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum City {
+    Amsterdam,
+    Berlin,
+    Paris,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum DeliveryOption {
+    Express,
+    Gift,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum Payment {
+    Card,
+    Cash,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"nested_widgets\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .branch(dptree::case![State::Idle].endpoint(send_widget)),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Checkout(_w)].branch(CheckoutForm::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = CheckoutForm {
+        address: AddressForm {
+            city: RadioList::from_iter([City::Amsterdam, City::Berlin, City::Paris]),
+            options: CheckboxList::from_iter([DeliveryOption::Express, DeliveryOption::Gift]),
+        },
+        payment: RadioList::from_iter([Payment::Card, Payment::Cash]),
+        checkout_button: Button::new("Checkout"),
+    };
+
+    bot.send_message(message.chat.id, "Fill in the checkout form:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Checkout(widget)).await?;
+
+    Ok(())
+}
+
+async fn process_checkout(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: CheckoutForm,
+) -> HandlerResult {
+    let message = cq.message.unwrap();
+
+    bot.answer_callback_query(cq.id).await?;
+
+    let city = widget.address.city.active_item().map(ToString::to_string);
+    let payment = widget.payment.active_item().map(ToString::to_string);
+    bot.send_message(
+        message.chat.id,
+        format!(
+            "City: {}, payment: {}",
+            city.unwrap_or("not chosen".to_owned()),
+            payment.unwrap_or("not chosen".to_owned())
+        ),
+    )
+    .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
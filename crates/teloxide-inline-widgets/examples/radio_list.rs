@@ -22,7 +22,7 @@ enum State {
 #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
 #[inline_widget(state = State::ChoosingFruit)]
 struct ChooseFruitWidget {
-    #[radio_list(prefix = "f_")]
+    #[radio_list(prefix = "f_", on_change = log_chosen_fruit)]
     pub fruits: RadioList<Fruit>,
 }
 
@@ -91,3 +91,13 @@ async fn send_widget(
 
     Ok(())
 }
+
+async fn log_chosen_fruit(
+    widget: ChooseFruitWidget,
+    RadioListItemIndex(i): RadioListItemIndex,
+) -> HandlerResult {
+    let fruit = &widget.fruits.items()[i];
+    log::info!("The fruit #{i} is chosen: {fruit}");
+
+    Ok(())
+}
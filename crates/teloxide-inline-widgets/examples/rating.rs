@@ -0,0 +1,101 @@
+//! This example demonstrates how to use the `Rating` widget to collect a
+//! feedback.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, Rating};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    RatingService(FeedbackWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::RatingService)]
+struct FeedbackWidget {
+    #[rating(prefix = "r_", clearable, on_change = log_rating)]
+    pub rating: Rating,
+    #[button(data = "send", click = send_feedback)]
+    pub send_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"rating\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::RatingService(_w)].branch(FeedbackWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = FeedbackWidget { rating: Rating::new(5, None), send_button: Button::new("Send") };
+
+    bot.send_message(message.chat.id, "How do you like our service?")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::RatingService(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_rating(rating: Option<u8>) -> HandlerResult {
+    log::info!("The rating is changed to {rating:?}");
+
+    Ok(())
+}
+
+async fn send_feedback(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: FeedbackWidget,
+) -> HandlerResult {
+    let Some(rating) = widget.rating.rating() else {
+        bot.answer_callback_query(cq.id).text("Rate the service first").await?;
+        return Ok(());
+    };
+    bot.answer_callback_query(cq.id).await?;
+
+    bot.send_message(cq.message.unwrap().chat.id, format!("Thanks for the {rating}-star rating!"))
+        .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
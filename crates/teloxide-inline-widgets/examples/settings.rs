@@ -0,0 +1,114 @@
+//! This example demonstrates how to assemble a settings screen with the
+//! `Toggle` widgets.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, Toggle};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    EditingSettings(SettingsWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::EditingSettings)]
+struct SettingsWidget {
+    #[toggle(data = "notifications", on_change = log_notifications)]
+    pub notifications: Toggle,
+    #[toggle(data = "sounds")]
+    pub sounds: Toggle,
+    #[toggle(data = "dark_theme")]
+    pub dark_theme: Toggle,
+    #[button(data = "save", click = save_settings)]
+    pub save_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"settings\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::EditingSettings(_w)].branch(SettingsWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = SettingsWidget {
+        notifications: Toggle::new("Notifications", true),
+        sounds: Toggle::new("Sounds", true),
+        dark_theme: Toggle::new("Dark theme", false),
+        save_button: Button::new("Save"),
+    };
+
+    bot.send_message(message.chat.id, "Settings:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::EditingSettings(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_notifications(is_on: bool) -> HandlerResult {
+    log::info!("The notifications are {}", if is_on { "enabled" } else { "disabled" });
+
+    Ok(())
+}
+
+async fn save_settings(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: SettingsWidget,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    bot.send_message(
+        cq.message.unwrap().chat.id,
+        format!(
+            "Saved: notifications - {}, sounds - {}, dark theme - {}",
+            widget.notifications.is_on(),
+            widget.sounds.is_on(),
+            widget.dark_theme.is_on()
+        ),
+    )
+    .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! This example demonstrates how to use the `SortableList` widget to reorder
+//! the items.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, SortableList};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Ordering(PlaylistWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Ordering)]
+struct PlaylistWidget {
+    #[sortable_list(prefix = "p_", on_change = log_playlist)]
+    pub playlist: SortableList<String>,
+    #[button(data = "save", click = save_playlist)]
+    pub save_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"sortable_list\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Ordering(_w)].branch(PlaylistWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = PlaylistWidget {
+        playlist: SortableList::from(vec![
+            "Intro".to_owned(),
+            "Main theme".to_owned(),
+            "Interlude".to_owned(),
+            "Outro".to_owned(),
+        ]),
+        save_button: Button::new("💾 Save"),
+    };
+
+    bot.send_message(message.chat.id, "Reorder the playlist:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Ordering(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_playlist(widget: PlaylistWidget) -> HandlerResult {
+    log::info!("The playlist is reordered: {:?}", widget.playlist.items());
+
+    Ok(())
+}
+
+async fn save_playlist(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: PlaylistWidget,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    let playlist = widget
+        .playlist
+        .into_items()
+        .into_iter()
+        .enumerate()
+        .map(|(i, track)| format!("{}. {track}", i + 1))
+        .collect::<Vec<_>>();
+    bot.send_message(cq.message.unwrap().chat.id, playlist.join("\n")).await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! This example demonstrates how to use the `Stepper` widget as a quantity
+//! input.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, Stepper};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    ChoosingQuantity(ChooseQuantityWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::ChoosingQuantity)]
+struct ChooseQuantityWidget {
+    #[stepper(prefix = "q_", on_change = log_quantity)]
+    pub quantity: Stepper<u32>,
+    #[button(data = "order", click = make_order)]
+    pub order_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"stepper\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(
+                    dptree::case![State::ChoosingQuantity(_w)]
+                        .branch(ChooseQuantityWidget::schema()),
+                ),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let mut quantity = Stepper::new(1, 1, 99, 1);
+    quantity.set_big_step(Some(10));
+    quantity.set_presets([5, 10, 25, 50, 75]);
+    let widget = ChooseQuantityWidget { quantity, order_button: Button::new("Order") };
+
+    bot.send_message(message.chat.id, "How many pizzas do you want?")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::ChoosingQuantity(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_quantity(quantity: u32) -> HandlerResult {
+    log::info!("The quantity is changed to {quantity}");
+
+    Ok(())
+}
+
+async fn make_order(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: ChooseQuantityWidget,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    bot.send_message(
+        cq.message.unwrap().chat.id,
+        format!("You've ordered {} pizzas", widget.quantity.value()),
+    )
+    .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
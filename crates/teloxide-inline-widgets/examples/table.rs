@@ -0,0 +1,100 @@
+//! This example demonstrates how to use the `Table` widget to display the
+//! tabular report.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Table};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Viewing(ReportWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Viewing)]
+struct ReportWidget {
+    #[table(prefix = "o_", on_click = show_order)]
+    pub orders: Table,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"table\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Viewing(_w)].branch(ReportWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let orders = [
+        ["#1001", "Alice", "25.00"],
+        ["#1002", "Bob", "7.50"],
+        ["#1003", "Carol", "120.00"],
+        ["#1004", "Dave", "42.10"],
+        ["#1005", "Eve", "3.99"],
+    ];
+    let widget = ReportWidget {
+        orders: Table::new(
+            ["Order", "Customer", "Sum"],
+            orders.map(|row| row.map(ToOwned::to_owned).to_vec()),
+            3,
+        ),
+    };
+
+    bot.send_message(message.chat.id, "Orders:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Viewing(widget)).await?;
+
+    Ok(())
+}
+
+async fn show_order(
+    bot: Bot,
+    cq: CallbackQuery,
+    widget: ReportWidget,
+    cell: TableCell,
+) -> HandlerResult {
+    let row = &widget.orders.rows()[cell.row];
+    bot.answer_callback_query(cq.id)
+        .text(format!("Order {} by {}: {}", row[0], row[1], row[2]))
+        .await?;
+
+    Ok(())
+}
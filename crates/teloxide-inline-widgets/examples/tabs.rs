@@ -0,0 +1,110 @@
+//! This example demonstrates how to use the `Tabs` widget to switch between
+//! several widgets.
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{
+    prelude::*, types::WidgetStyles, Calendar, CheckboxList, RadioList, Tabs,
+};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Searching(SearchWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Searching)]
+struct SearchWidget {
+    #[tabs(prefix = "t_")]
+    pub tabs: Tabs,
+    // The tabs are displayed in the declaration order
+    #[checkbox_list(prefix = "f_")]
+    pub filters: CheckboxList<Filter>,
+    #[radio_list(prefix = "s_", on_change = log_sort_order)]
+    pub sort_order: RadioList<SortOrder>,
+    #[calendar]
+    pub date: Calendar,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum Filter {
+    #[display(fmt = "In stock")]
+    InStock,
+    #[display(fmt = "Free delivery")]
+    FreeDelivery,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum SortOrder {
+    #[display(fmt = "Price")]
+    Price,
+    #[display(fmt = "Rating")]
+    Rating,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"tabs\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Searching(_w)].branch(SearchWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = SearchWidget {
+        tabs: Tabs::new(["Filters", "Sort", "Date"]),
+        filters: CheckboxList::from(vec![Filter::InStock, Filter::FreeDelivery]),
+        sort_order: RadioList::from(vec![SortOrder::Price, SortOrder::Rating]),
+        date: Calendar::new(),
+    };
+
+    bot.send_message(message.chat.id, "Search options:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Searching(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_sort_order(widget: SearchWidget) -> HandlerResult {
+    log::info!("The sort order is changed to {:?}", widget.sort_order.active_item());
+
+    Ok(())
+}
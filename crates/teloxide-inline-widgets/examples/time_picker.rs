@@ -0,0 +1,95 @@
+//! This example demonstrates how to use the `TimePicker` widget.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, TimePicker};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    ChoosingTime(ChooseTimeWidget),
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::ChoosingTime)]
+struct ChooseTimeWidget {
+    #[time_picker(prefix = "t_", on_confirm = set_alarm)]
+    pub time_picker: TimePicker,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"time_picker\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::ChoosingTime(_w)].branch(ChooseTimeWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let mut widget = ChooseTimeWidget {
+        time_picker: TimePicker::new(NaiveTime::from_hms_opt(7, 30, 0).unwrap()),
+    };
+    widget.time_picker.set_minute_step(5);
+    widget.time_picker.set_twelve_hour(true);
+
+    bot.send_message(message.chat.id, "Set the alarm:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::ChoosingTime(widget)).await?;
+
+    Ok(())
+}
+
+async fn set_alarm(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    time: NaiveTime,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    bot.send_message(
+        cq.message.unwrap().chat.id,
+        format!("The alarm is set to {}", time.format("%H:%M")),
+    )
+    .await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
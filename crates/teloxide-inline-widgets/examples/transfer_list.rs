@@ -0,0 +1,105 @@
+//! This example demonstrates how to use the `TransferList` widget to choose
+//! the ordered subset of the items.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, Button, TransferList};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Choosing(PrioritiesWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Choosing)]
+struct PrioritiesWidget {
+    #[transfer_list(prefix = "t_", on_change = log_priorities)]
+    pub tasks: TransferList<String>,
+    #[button(data = "done", click = save_priorities)]
+    pub done_button: Button,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"transfer_list\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Choosing(_w)].branch(PrioritiesWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let tasks = (1..=12).map(|i| format!("Task #{i}"));
+    let widget =
+        PrioritiesWidget { tasks: TransferList::new(tasks, 4), done_button: Button::new("Done") };
+
+    bot.send_message(message.chat.id, "Choose the tasks in the order of priority:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Choosing(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_priorities(widget: PrioritiesWidget) -> HandlerResult {
+    log::info!("The chosen tasks: {:?}", widget.tasks.chosen());
+
+    Ok(())
+}
+
+async fn save_priorities(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: PrioritiesWidget,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    let priorities = widget
+        .tasks
+        .into_chosen()
+        .into_iter()
+        .enumerate()
+        .map(|(i, task)| format!("{}. {task}", i + 1))
+        .collect::<Vec<_>>();
+    bot.send_message(cq.message.unwrap().chat.id, priorities.join("\n")).await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! This example demonstrates how to use the `TreeView` widget to select the
+//! items of the hierarchical categories.
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{prelude::*, types::WidgetStyles, TreeView};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Browsing(InventoryWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Browsing)]
+struct InventoryWidget {
+    #[tree_view(prefix = "c_", on_change = log_selection)]
+    pub categories: TreeView<String>,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"tree_view\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Browsing(_w)].branch(InventoryWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let leaf = |item: &str| TreeNode::leaf(item.to_owned());
+    let mut categories = TreeView::new([
+        TreeNode::branch(
+            "Electronics",
+            [
+                TreeNode::branch("Phones", [leaf("Smartphones"), leaf("Landlines")]),
+                TreeNode::branch("Computers", [leaf("Laptops"), leaf("Desktops")]),
+            ],
+        ),
+        TreeNode::branch(
+            "Home",
+            [TreeNode::branch("Kitchen", [leaf("Cookware"), leaf("Cutlery")]), leaf("Furniture")],
+        ),
+    ]);
+    categories.set_selection_mode(TreeViewSelectionMode::Multiple);
+    let widget = InventoryWidget { categories };
+
+    bot.send_message(message.chat.id, "Choose the categories:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Browsing(widget)).await?;
+
+    Ok(())
+}
+
+async fn log_selection(widget: InventoryWidget) -> HandlerResult {
+    log::info!(
+        "The selected categories: {:?}",
+        widget.categories.selected_items().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! This example demonstrates how to use the `Wizard` widget to fill several
+//! widgets step by step.
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use teloxide::{dispatching::dialogue::InMemStorage, prelude::*};
+use teloxide_inline_widgets::{
+    prelude::*, types::WidgetStyles, CheckboxList, RadioList, Rating, Wizard,
+};
+
+type Bot = teloxide::Bot;
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+type HandlerResult = Result<(), Error>;
+type UpdateHandler = teloxide::dispatching::UpdateHandler<Error>;
+type Storage = InMemStorage<State>;
+type Dialogue = teloxide::dispatching::dialogue::Dialogue<State, Storage>;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+enum State {
+    #[default]
+    Idle,
+    Onboarding(OnboardingWidget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, InlineWidget)]
+#[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue)]
+#[inline_widget(state = State::Onboarding)]
+struct OnboardingWidget {
+    #[wizard(prefix = "w_", validate = validate_step, on_finish = finish_onboarding)]
+    pub wizard: Wizard,
+    // The steps are displayed in the declaration order
+    #[radio_list(prefix = "l_")]
+    pub language: RadioList<Language>,
+    #[checkbox_list(prefix = "t_")]
+    pub topics: CheckboxList<Topic>,
+    #[rating(prefix = "e_")]
+    pub experience: Rating,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum Language {
+    #[display(fmt = "English")]
+    English,
+    #[display(fmt = "Deutsch")]
+    German,
+}
+
+#[derive(Debug, Display, Clone, Deserialize, Serialize)]
+enum Topic {
+    #[display(fmt = "News")]
+    News,
+    #[display(fmt = "Sports")]
+    Sports,
+    #[display(fmt = "Music")]
+    Music,
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    log::info!("Example \"wizard\" started..");
+
+    let state_storage = InMemStorage::<State>::new();
+
+    Dispatcher::builder(Bot::from_env(), schema())
+        .dependencies(dptree::deps![state_storage, WidgetStyles::default()])
+        .build()
+        .dispatch()
+        .await;
+}
+
+fn schema() -> UpdateHandler {
+    dptree::entry()
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, Storage, State>()
+                .endpoint(send_widget),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .enter_dialogue::<CallbackQuery, Storage, State>()
+                .branch(dptree::case![State::Onboarding(_w)].branch(OnboardingWidget::schema())),
+        )
+}
+
+async fn send_widget(
+    bot: Bot,
+    dialogue: Dialogue,
+    message: Message,
+    widget_styles: WidgetStyles,
+) -> HandlerResult {
+    let widget = OnboardingWidget {
+        wizard: Wizard::new(3),
+        language: RadioList::from(vec![Language::English, Language::German]),
+        topics: CheckboxList::from(vec![Topic::News, Topic::Sports, Topic::Music]),
+        experience: Rating::new(5, None),
+    };
+
+    bot.send_message(message.chat.id, "Let's set up your profile:")
+        .reply_markup(widget.inline_keyboard_markup(&widget_styles))
+        .await?;
+
+    dialogue.update(State::Onboarding(widget)).await?;
+
+    Ok(())
+}
+
+fn validate_step(widget: &OnboardingWidget, step: usize) -> Result<(), String> {
+    match step {
+        0 if widget.language.active_item().is_none() => Err("Choose the language".to_owned()),
+        1 if widget.topics.selected_items().next().is_none() => {
+            Err("Choose at least one topic".to_owned())
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn finish_onboarding(
+    bot: Bot,
+    dialogue: Dialogue,
+    cq: CallbackQuery,
+    widget: OnboardingWidget,
+) -> HandlerResult {
+    bot.answer_callback_query(cq.id).await?;
+
+    let topics = widget.topics.selected_items().map(ToString::to_string).collect::<Vec<_>>();
+    let text = format!(
+        "Language: {}\nTopics: {}\nExperience: {}",
+        widget.language.active_item().unwrap(),
+        topics.join(", "),
+        widget.experience.rating().map_or("not rated".to_owned(), |rating| rating.to_string()),
+    );
+    bot.send_message(cq.message.unwrap().chat.id, text).await?;
+
+    dialogue.update(State::Idle).await?;
+
+    Ok(())
+}
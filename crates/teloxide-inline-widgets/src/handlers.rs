@@ -0,0 +1,75 @@
+//! Reusable [`dptree`]-handlers for the widgets' schemas. They are mostly used
+//! by the [`#[derive(InlineWidget)`] macro
+use std::{ops::ControlFlow, sync::Arc};
+
+use teloxide::{
+    dispatching::{DpHandlerDescription, UpdateHandler},
+    dptree::{
+        self,
+        di::{DependencyMap, DependencySupplier},
+    },
+    prelude::Requester,
+    types::{ChatId, MessageId},
+};
+
+use crate::{traits::InlineWidget, types::WidgetStyles};
+
+/// Returns the handler which answers the callback query, redraws the `W`
+/// widget and updates its state, then passes the control to the next handler
+/// in the chain.
+///
+/// The `W` widget is supposed to be already changed by the previous handlers
+pub fn update_widget<W>() -> UpdateHandler<W::Err>
+where
+    W: 'static + Clone + Send + Sync + InlineWidget,
+    W::Bot: 'static + Clone + Send + Sync,
+    W::Dialogue: 'static + Clone + Send + Sync,
+{
+    widget_updater::<W>(true)
+}
+
+/// Same as [`update_widget`], but leaves the callback query unanswered, so the
+/// next handler in the chain is supposed to answer it
+pub fn redraw_widget<W>() -> UpdateHandler<W::Err>
+where
+    W: 'static + Clone + Send + Sync + InlineWidget,
+    W::Bot: 'static + Clone + Send + Sync,
+    W::Dialogue: 'static + Clone + Send + Sync,
+{
+    widget_updater::<W>(false)
+}
+
+fn widget_updater<W>(answer_callback_query: bool) -> UpdateHandler<W::Err>
+where
+    W: 'static + Clone + Send + Sync + InlineWidget,
+    W::Bot: 'static + Clone + Send + Sync,
+    W::Dialogue: 'static + Clone + Send + Sync,
+{
+    dptree::from_fn::<_, _, _, _, DpHandlerDescription>(
+        move |deps: DependencyMap, cont| async move {
+            let widget: Arc<W> = deps.get();
+            let bot: Arc<W::Bot> = deps.get();
+            let dialogue: Arc<W::Dialogue> = deps.get();
+            let widget_styles: Arc<WidgetStyles> = deps.get();
+            let cq_message: Arc<(ChatId, MessageId, String)> = deps.get();
+            let (chat_id, message_id, cq_id) = cq_message.as_ref().clone();
+
+            let result = async {
+                if answer_callback_query {
+                    bot.answer_callback_query(cq_id).await?;
+                }
+
+                // It's safe to update the view (keyboard) before the state if updates are
+                // processed consistently in a single chat, so there is no races
+                widget.redraw(&bot, chat_id, message_id, &widget_styles).await?;
+                W::clone(&widget).update_state(&dialogue).await
+            }
+            .await;
+
+            match result {
+                Ok(()) => cont(deps).await,
+                Err(err) => ControlFlow::Break(Err(err)),
+            }
+        },
+    )
+}
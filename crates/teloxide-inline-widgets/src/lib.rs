@@ -1,3 +1,4 @@
+pub mod handlers;
 pub mod layout;
 pub mod prelude;
 pub mod traits;
@@ -5,6 +6,12 @@ pub mod types;
 
 mod widgets;
 
-pub use widgets::{Button, Calendar, CheckboxList, RadioList};
+#[cfg(test)]
+mod test_utils;
+
+pub use widgets::{
+    Button, Calendar, CheckboxList, DateTimePicker, Dropdown, Keypad, Menu, RadioList, Rating,
+    SortableList, Stepper, Table, Tabs, TimePicker, Toggle, TransferList, TreeView, Wizard,
+};
 
 // TODO docs
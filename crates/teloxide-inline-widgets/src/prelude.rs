@@ -1,4 +1,4 @@
-pub use chrono::{NaiveDate, Weekday};
+pub use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 pub use log;
 pub use teloxide::types::{CallbackQuery, MessageId};
 pub use teloxide_inline_widgets_macros::InlineWidget;
@@ -6,6 +6,16 @@ pub use teloxide_inline_widgets_macros::InlineWidget;
 pub use crate::{
     layout::{Layout, LayoutOrientation},
     traits::{GetSize, InlineWidget, WidgetContainer},
-    types::{CallbackQueryData, Size, WidgetStyles},
-    widgets::{CalendarSchemaParameters, CheckboxListSchemaParameters, RadioListSchemaParameters},
+    types::{CallbackQueryData, NestedWidget, Size, WidgetStyles},
+    widgets::{
+        ButtonConfirmParameters, CalendarSchemaParameters, CalendarSelectionMode, CalendarView,
+        CheckboxListItemIndex, CheckboxListLimit, CheckboxListSchemaParameters,
+        DateTimePickerSchemaParameters, DateTimePickerStep, DropdownSchemaParameters, KeypadInput,
+        KeypadSchemaParameters, MenuNode, MenuSchemaParameters, RadioListItemIndex,
+        RadioListSchemaParameters, RatingSchemaParameters, SortableListSchemaParameters,
+        StepperSchemaParameters, TableCell, TableSchemaParameters, TableSortOrder,
+        TabsSchemaParameters, TimePickerSchemaParameters, ToggleSchemaParameters,
+        TransferListSchemaParameters, TreeNode, TreeViewSchemaParameters, TreeViewSelectionMode,
+        WizardSchemaParameters, WizardValidator,
+    },
 };
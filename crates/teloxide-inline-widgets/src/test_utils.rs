@@ -0,0 +1,168 @@
+//! Helpers for dispatching the callback queries through the widgets' schemas
+//! in tests. The requests to the Telegram API are served in-process
+use std::{
+    ops::ControlFlow,
+    sync::{Arc, Mutex},
+};
+
+use teloxide::{
+    dispatching::dialogue::{Dialogue, InMemStorage, Storage},
+    dptree,
+    types::{CallbackQuery, ChatId},
+    Bot,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::{traits::InlineWidget, types::WidgetStyles};
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Dialogue of the test widget `W`, the state is the widget itself. Use it
+/// with the `state = Some` attribute
+pub(crate) type TestDialogue<W> = Dialogue<Option<W>, InMemStorage<Option<W>>>;
+
+/// Request to the Telegram API
+#[derive(Debug)]
+pub(crate) struct Request {
+    /// Method name, e.g. `AnswerCallbackQuery`
+    pub method: String,
+    pub body: serde_json::Value,
+}
+
+/// Outcome of the callback query dispatching
+#[derive(Debug)]
+pub(crate) struct Dispatched<W> {
+    /// Whether the callback query was handled by the schema
+    pub handled: bool,
+    pub requests: Vec<Request>,
+    /// The widget saved to the dialogue, `None` if the widget wasn't updated
+    pub widget: Option<W>,
+}
+
+impl<W> Dispatched<W> {
+    /// Returns the methods of the requests in the order they were sent
+    pub fn methods(&self) -> Vec<&str> {
+        self.requests.iter().map(|request| request.method.as_str()).collect()
+    }
+
+    /// Returns `true` if the callback query was only answered and the widget
+    /// wasn't changed, that's how the stale callback queries are handled
+    pub fn is_ignored(&self) -> bool {
+        self.handled && self.methods() == ["AnswerCallbackQuery"] && self.widget.is_none()
+    }
+}
+
+/// Returns the callback query with the `data` sent from the widget message
+pub(crate) fn callback_query(data: &str) -> CallbackQuery {
+    serde_json::from_value(serde_json::json!({
+        "id": "0",
+        "from": { "id": 1, "is_bot": false, "first_name": "User" },
+        "message": {
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private", "first_name": "User" },
+            "text": "Widget"
+        },
+        "chat_instance": "0",
+        "data": data
+    }))
+    .unwrap()
+}
+
+/// Dispatches the callback query with the `data` through the `W` widget
+/// schema
+///
+/// Panics if the schema returns an error
+pub(crate) async fn dispatch<W>(widget: W, data: &str) -> Dispatched<W>
+where
+    W: 'static
+        + Clone
+        + Send
+        + Sync
+        + InlineWidget<Bot = Bot, Err = Error, Dialogue = TestDialogue<W>>,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let api_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let server = tokio::spawn(serve(listener, requests.clone()));
+
+    let bot = Bot::new("token").set_api_url(api_url.parse().unwrap());
+    let storage = InMemStorage::<Option<W>>::new();
+    let dialogue = Dialogue::new(storage.clone(), ChatId(1));
+    let result = W::schema()
+        .dispatch(dptree::deps![
+            widget,
+            bot,
+            dialogue,
+            WidgetStyles::default(),
+            callback_query(data)
+        ])
+        .await;
+    server.abort();
+
+    let handled = match result {
+        ControlFlow::Break(Ok(())) => true,
+        ControlFlow::Break(Err(err)) => panic!("Schema returned an error: {err}"),
+        ControlFlow::Continue(_) => false,
+    };
+    let widget = storage.get_dialogue(ChatId(1)).await.unwrap().flatten();
+    let requests = std::mem::take(&mut *requests.lock().unwrap());
+
+    Dispatched { handled, requests, widget }
+}
+
+/// Serves the Telegram API requests, one per connection
+async fn serve(listener: TcpListener, requests: Arc<Mutex<Vec<Request>>>) {
+    while let Ok((mut stream, _)) = listener.accept().await {
+        let Some(request) = read_request(&mut stream).await else { continue };
+
+        // The message is returned for all the methods, except the ones returning `true`
+        let result = if request.method == "AnswerCallbackQuery" {
+            serde_json::json!(true)
+        } else {
+            callback_query("").message.map(serde_json::to_value).unwrap().unwrap()
+        };
+        requests.lock().unwrap().push(request);
+
+        let body = serde_json::json!({ "ok": true, "result": result }).to_string();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: \
+             {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut buf = vec![];
+    let headers_end = loop {
+        if let Some(i) = buf.windows(4).position(|window| window == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let mut chunk = [0; 1024];
+        let n = stream.read(&mut chunk).await.ok().filter(|&n| n > 0)?;
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let headers = String::from_utf8_lossy(&buf[..headers_end]).into_owned();
+    let method = headers.split_whitespace().nth(1)?.rsplit('/').next()?.to_owned();
+    let content_length = headers
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse().ok())?
+        })
+        .unwrap_or(0);
+    while buf.len() < headers_end + content_length {
+        let mut chunk = [0; 1024];
+        let n = stream.read(&mut chunk).await.ok().filter(|&n| n > 0)?;
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = serde_json::from_slice(&buf[headers_end..]).unwrap_or_default();
+
+    Some(Request { method, body })
+}
@@ -1,5 +1,8 @@
 mod callback_query_data;
+mod nested_widget;
 mod size;
 mod styles;
 
-pub use self::{callback_query_data::CallbackQueryData, size::Size, styles::*};
+pub use self::{
+    callback_query_data::CallbackQueryData, nested_widget::NestedWidget, size::Size, styles::*,
+};
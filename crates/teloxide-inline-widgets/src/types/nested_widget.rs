@@ -0,0 +1,131 @@
+use std::{future::Future, marker::PhantomData};
+
+use teloxide::{dispatching::UpdateHandler, types::InlineKeyboardMarkup};
+
+use crate::{
+    traits::{InlineWidget, WidgetContainer},
+    types::WidgetStyles,
+};
+
+/// Wraps the `root` widget `W` to provide access to the components of the
+/// `user-defined` widget `C` nested into it.
+///
+/// The components are retrieved through the `C` widget, while the view and
+/// the state are managed by the `root` widget. The callbacks of the components
+/// (e.g. `on_change`) receive the updated [`NestedWidget`], so use
+/// [`NestedWidget::into_root`] to get the `root` widget.
+///
+/// It's not supposed to be used directly, the [`#[derive(InlineWidget)`]
+/// macro uses it for the fields of the `user-defined` widget types
+#[derive(Debug, Clone)]
+pub struct NestedWidget<W, C> {
+    root: W,
+    _nested: PhantomData<C>,
+}
+
+impl<W, C> NestedWidget<W, C> {
+    /// Creates new [`NestedWidget`] instance from the `root` widget
+    pub fn new(root: W) -> Self {
+        Self { root, _nested: PhantomData }
+    }
+
+    /// Returns the `root` widget
+    pub fn into_root(self) -> W {
+        self.root
+    }
+}
+
+impl<W, C, T> WidgetContainer<T> for NestedWidget<W, C>
+where
+    W: WidgetContainer<C>,
+    C: WidgetContainer<T>,
+{
+    fn get_widget(&mut self) -> &mut T {
+        self.root.get_widget().get_widget()
+    }
+}
+
+impl<W, C> InlineWidget for NestedWidget<W, C>
+where
+    W: InlineWidget,
+{
+    type Bot = W::Bot;
+    type Err = W::Err;
+    type Dialogue = W::Dialogue;
+
+    fn schema() -> UpdateHandler<Self::Err> {
+        W::schema()
+    }
+
+    fn inline_keyboard_markup(&self, styles: &WidgetStyles) -> InlineKeyboardMarkup {
+        self.root.inline_keyboard_markup(styles)
+    }
+
+    fn update_state(
+        self,
+        dialogue: &Self::Dialogue,
+    ) -> impl Future<Output = Result<(), Self::Err>> + Send {
+        self.root.update_state(dialogue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::{dptree, types::InlineKeyboardButtonKind};
+
+    use crate::{prelude::*, RadioList};
+
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = teloxide::Bot)]
+    struct Child {
+        #[radio_list(prefix = "rl_")]
+        radio_list: RadioList<u8>,
+    }
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = teloxide::Bot)]
+    struct Parent {
+        child: Child,
+        #[radio_list(prefix = "p_")]
+        radio_list: RadioList<u8>,
+    }
+
+    fn parent() -> Parent {
+        Parent {
+            child: Child { radio_list: RadioList::new([1, 2], None, Size::new(1, 2)) },
+            radio_list: RadioList::new([3], None, Size::new(1, 1)),
+        }
+    }
+
+    #[test]
+    fn nested_markup() {
+        let parent = parent();
+        assert_eq!(parent.size(), Size::new(2, 2));
+
+        let keyboard = parent.inline_keyboard_markup(&WidgetStyles::default()).inline_keyboard;
+        let data: Vec<Vec<_>> = keyboard
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|button| match &button.kind {
+                        InlineKeyboardButtonKind::CallbackData(data) => data.as_str(),
+                        _ => unreachable!(),
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(data, vec![vec!["rl_0", "rl_1"], vec!["p_0", "noop"]]);
+    }
+
+    #[test]
+    fn nested_components_access() {
+        let mut nested = NestedWidget::<Parent, Child>::new(parent());
+        WidgetContainer::<RadioList<u8>>::get_widget(&mut nested).set_active(1);
+        assert_eq!(nested.into_root().child.radio_list.active_item(), Some(&2));
+
+        // The schema of the root widget includes the schema of the nested one
+        let _schema = Parent::schema();
+    }
+}
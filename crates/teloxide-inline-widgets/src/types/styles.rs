@@ -1,10 +1,28 @@
+mod button_style;
 mod calendar_style;
 mod checkbox_list_style;
 mod common_style;
+mod date_time_picker_style;
+mod dropdown_style;
+mod keypad_style;
+mod menu_style;
 mod radio_list_style;
+mod rating_style;
+mod sortable_list_style;
+mod stepper_style;
+mod table_style;
+mod tabs_style;
+mod time_picker_style;
+mod toggle_style;
+mod transfer_list_style;
+mod tree_view_style;
 mod widget_styles;
+mod wizard_style;
 
 pub use self::{
-    calendar_style::*, checkbox_list_style::*, common_style::*, radio_list_style::*,
-    widget_styles::*,
+    button_style::*, calendar_style::*, checkbox_list_style::*, common_style::*,
+    date_time_picker_style::*, dropdown_style::*, keypad_style::*, menu_style::*,
+    radio_list_style::*, rating_style::*, sortable_list_style::*, stepper_style::*, table_style::*,
+    tabs_style::*, time_picker_style::*, toggle_style::*, transfer_list_style::*,
+    tree_view_style::*, widget_styles::*, wizard_style::*,
 };
@@ -0,0 +1,60 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Button`] widget style
+#[derive(Debug, Clone)]
+pub struct ButtonStyle {
+    /// Text of the button which confirms the click
+    pub yes_text: Cow<'static, str>,
+    /// Text of the button which cancels the click
+    pub no_text: Cow<'static, str>,
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self { yes_text: Cow::Borrowed("✅ Yes"), no_text: Cow::Borrowed("❌ No") }
+    }
+}
+
+impl ButtonStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> ButtonStyleBuilder {
+        ButtonStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct ButtonStyleBuilder {
+    /// Text of the button which confirms the click
+    pub yes_text: Cow<'static, str>,
+    /// Text of the button which cancels the click
+    pub no_text: Cow<'static, str>,
+}
+
+impl Default for ButtonStyleBuilder {
+    fn default() -> Self {
+        Self { yes_text: Cow::Borrowed("✅ Yes"), no_text: Cow::Borrowed("❌ No") }
+    }
+}
+
+impl ButtonStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<ButtonStyle> {
+        Arc::new(ButtonStyle { yes_text: self.yes_text, no_text: self.no_text })
+    }
+
+    pub fn yes_text(mut self, value: Cow<'static, str>) -> Self {
+        self.yes_text = value;
+        self
+    }
+
+    pub fn no_text(mut self, value: Cow<'static, str>) -> Self {
+        self.no_text = value;
+        self
+    }
+}
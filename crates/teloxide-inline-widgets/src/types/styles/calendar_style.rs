@@ -11,10 +11,27 @@ pub struct CalendarStyle {
     pub previous_year_icon: Cow<'static, str>,
     /// Icon for `choose next year` button
     pub next_year_icon: Cow<'static, str>,
-    /// Names of the days of the week
+    /// Icon for `return to the days` button of the months and the years views
+    pub days_view_icon: Cow<'static, str>,
+    /// Names of the days of the week, starting with Monday
     pub days_of_the_week: [Cow<'static, str>; 7],
     /// Names of months
     pub months: [Cow<'static, str>; 12],
+    /// Format of the days that can't be clicked, `{day}` is replaced with the
+    /// day of the month
+    pub disabled_day_format: Cow<'static, str>,
+    /// Format of the months and the years beyond the date bounds, `{cell}` is
+    /// replaced with the month name or the year
+    pub disabled_cell_format: Cow<'static, str>,
+    /// Format of the selected day, `{day}` is replaced with the day of the
+    /// month
+    pub selected_day_format: Cow<'static, str>,
+    /// Format of the days between the start and the end of the selected range,
+    /// `{day}` is replaced with the day of the month
+    pub range_day_format: Cow<'static, str>,
+    /// Format of the current day, `{day}` is replaced with the day of the
+    /// month
+    pub today_format: Cow<'static, str>,
 }
 
 impl Default for CalendarStyle {
@@ -24,6 +41,7 @@ impl Default for CalendarStyle {
             next_month_icon: Cow::Borrowed("▶️"),
             previous_year_icon: Cow::Borrowed("◀️"),
             next_year_icon: Cow::Borrowed("▶️"),
+            days_view_icon: Cow::Borrowed("↩️"),
             days_of_the_week: [
                 Cow::Borrowed("Mon"),
                 Cow::Borrowed("Tue"),
@@ -47,6 +65,11 @@ impl Default for CalendarStyle {
                 Cow::Borrowed("November"),
                 Cow::Borrowed("December"),
             ],
+            disabled_day_format: Cow::Borrowed("({day})"),
+            disabled_cell_format: Cow::Borrowed("({cell})"),
+            selected_day_format: Cow::Borrowed("[{day}]"),
+            range_day_format: Cow::Borrowed("~{day}~"),
+            today_format: Cow::Borrowed("•{day}"),
         }
     }
 }
@@ -71,10 +94,27 @@ pub struct CalendarStyleBuilder {
     pub previous_year_icon: Cow<'static, str>,
     /// Icon for `choose next year` button
     pub next_year_icon: Cow<'static, str>,
-    /// Names of the days of the week
+    /// Icon for `return to the days` button of the months and the years views
+    pub days_view_icon: Cow<'static, str>,
+    /// Names of the days of the week, starting with Monday
     pub days_of_the_week: [Cow<'static, str>; 7],
     /// Names of months
     pub months: [Cow<'static, str>; 12],
+    /// Format of the days that can't be clicked, `{day}` is replaced with the
+    /// day of the month
+    pub disabled_day_format: Cow<'static, str>,
+    /// Format of the months and the years beyond the date bounds, `{cell}` is
+    /// replaced with the month name or the year
+    pub disabled_cell_format: Cow<'static, str>,
+    /// Format of the selected day, `{day}` is replaced with the day of the
+    /// month
+    pub selected_day_format: Cow<'static, str>,
+    /// Format of the days between the start and the end of the selected range,
+    /// `{day}` is replaced with the day of the month
+    pub range_day_format: Cow<'static, str>,
+    /// Format of the current day, `{day}` is replaced with the day of the
+    /// month
+    pub today_format: Cow<'static, str>,
 }
 
 impl Default for CalendarStyleBuilder {
@@ -84,6 +124,7 @@ impl Default for CalendarStyleBuilder {
             next_month_icon: Cow::Borrowed("▶️"),
             previous_year_icon: Cow::Borrowed("◀️"),
             next_year_icon: Cow::Borrowed("▶️"),
+            days_view_icon: Cow::Borrowed("↩️"),
             days_of_the_week: [
                 Cow::Borrowed("Mon"),
                 Cow::Borrowed("Tue"),
@@ -107,6 +148,11 @@ impl Default for CalendarStyleBuilder {
                 Cow::Borrowed("November"),
                 Cow::Borrowed("December"),
             ],
+            disabled_day_format: Cow::Borrowed("({day})"),
+            disabled_cell_format: Cow::Borrowed("({cell})"),
+            selected_day_format: Cow::Borrowed("[{day}]"),
+            range_day_format: Cow::Borrowed("~{day}~"),
+            today_format: Cow::Borrowed("•{day}"),
         }
     }
 }
@@ -122,8 +168,14 @@ impl CalendarStyleBuilder {
             next_month_icon: self.next_month_icon,
             previous_year_icon: self.previous_year_icon,
             next_year_icon: self.next_year_icon,
+            days_view_icon: self.days_view_icon,
             days_of_the_week: self.days_of_the_week,
             months: self.months,
+            disabled_day_format: self.disabled_day_format,
+            disabled_cell_format: self.disabled_cell_format,
+            selected_day_format: self.selected_day_format,
+            range_day_format: self.range_day_format,
+            today_format: self.today_format,
         })
     }
 
@@ -147,6 +199,11 @@ impl CalendarStyleBuilder {
         self
     }
 
+    pub fn days_view_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.days_view_icon = value;
+        self
+    }
+
     pub fn days_of_the_week(mut self, value: [Cow<'static, str>; 7]) -> Self {
         self.days_of_the_week = value;
         self
@@ -156,4 +213,29 @@ impl CalendarStyleBuilder {
         self.months = value;
         self
     }
+
+    pub fn disabled_day_format(mut self, value: Cow<'static, str>) -> Self {
+        self.disabled_day_format = value;
+        self
+    }
+
+    pub fn disabled_cell_format(mut self, value: Cow<'static, str>) -> Self {
+        self.disabled_cell_format = value;
+        self
+    }
+
+    pub fn selected_day_format(mut self, value: Cow<'static, str>) -> Self {
+        self.selected_day_format = value;
+        self
+    }
+
+    pub fn range_day_format(mut self, value: Cow<'static, str>) -> Self {
+        self.range_day_format = value;
+        self
+    }
+
+    pub fn today_format(mut self, value: Cow<'static, str>) -> Self {
+        self.today_format = value;
+        self
+    }
 }
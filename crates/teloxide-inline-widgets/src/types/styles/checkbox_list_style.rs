@@ -7,11 +7,22 @@ pub struct CheckboxListStyle {
     pub active_icon: Cow<'static, str>,
     /// Icon of unselected item
     pub inactive_icon: Cow<'static, str>,
+    /// Text that is shown when the minimal number of selected items is
+    /// reached, `{limit}` is replaced with the limit value
+    pub min_selected_text: Cow<'static, str>,
+    /// Text that is shown when the maximal number of selected items is
+    /// reached, `{limit}` is replaced with the limit value
+    pub max_selected_text: Cow<'static, str>,
 }
 
 impl Default for CheckboxListStyle {
     fn default() -> Self {
-        Self { active_icon: Cow::Borrowed("☑"), inactive_icon: Cow::Borrowed("☐") }
+        Self {
+            active_icon: Cow::Borrowed("☑"),
+            inactive_icon: Cow::Borrowed("☐"),
+            min_selected_text: Cow::Borrowed("You must select at least {limit} item(s)"),
+            max_selected_text: Cow::Borrowed("You can select at most {limit} item(s)"),
+        }
     }
 }
 
@@ -29,11 +40,18 @@ impl CheckboxListStyle {
 pub struct CheckboxListStyleBuilder {
     pub active_icon: Cow<'static, str>,
     pub inactive_icon: Cow<'static, str>,
+    pub min_selected_text: Cow<'static, str>,
+    pub max_selected_text: Cow<'static, str>,
 }
 
 impl Default for CheckboxListStyleBuilder {
     fn default() -> Self {
-        Self { active_icon: Cow::Borrowed("☑"), inactive_icon: Cow::Borrowed("☐") }
+        Self {
+            active_icon: Cow::Borrowed("☑"),
+            inactive_icon: Cow::Borrowed("☐"),
+            min_selected_text: Cow::Borrowed("You must select at least {limit} item(s)"),
+            max_selected_text: Cow::Borrowed("You can select at most {limit} item(s)"),
+        }
     }
 }
 
@@ -46,6 +64,8 @@ impl CheckboxListStyleBuilder {
         Arc::new(CheckboxListStyle {
             active_icon: self.active_icon,
             inactive_icon: self.inactive_icon,
+            min_selected_text: self.min_selected_text,
+            max_selected_text: self.max_selected_text,
         })
     }
 
@@ -58,4 +78,14 @@ impl CheckboxListStyleBuilder {
         self.inactive_icon = value;
         self
     }
+
+    pub fn min_selected_text(mut self, value: Cow<'static, str>) -> Self {
+        self.min_selected_text = value;
+        self
+    }
+
+    pub fn max_selected_text(mut self, value: Cow<'static, str>) -> Self {
+        self.max_selected_text = value;
+        self
+    }
 }
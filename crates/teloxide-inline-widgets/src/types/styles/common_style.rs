@@ -4,11 +4,19 @@ use std::{borrow::Cow, sync::Arc};
 #[derive(Debug, Clone)]
 pub struct CommonStyle {
     pub empty_cell_icon: Cow<'static, str>,
+    /// Icon for `choose previous page` button of paginated widgets
+    pub previous_page_icon: Cow<'static, str>,
+    /// Icon for `choose next page` button of paginated widgets
+    pub next_page_icon: Cow<'static, str>,
 }
 
 impl Default for CommonStyle {
     fn default() -> Self {
-        Self { empty_cell_icon: Cow::Borrowed("✖️") }
+        Self {
+            empty_cell_icon: Cow::Borrowed("✖️"),
+            previous_page_icon: Cow::Borrowed("⬅️"),
+            next_page_icon: Cow::Borrowed("➡️"),
+        }
     }
 }
 
@@ -25,11 +33,19 @@ impl CommonStyle {
 #[derive(Debug)]
 pub struct CommonStyleBuilder {
     pub empty_cell_icon: Cow<'static, str>,
+    /// Icon for `choose previous page` button of paginated widgets
+    pub previous_page_icon: Cow<'static, str>,
+    /// Icon for `choose next page` button of paginated widgets
+    pub next_page_icon: Cow<'static, str>,
 }
 
 impl Default for CommonStyleBuilder {
     fn default() -> Self {
-        Self { empty_cell_icon: Cow::Borrowed("✖️") }
+        Self {
+            empty_cell_icon: Cow::Borrowed("✖️"),
+            previous_page_icon: Cow::Borrowed("⬅️"),
+            next_page_icon: Cow::Borrowed("➡️"),
+        }
     }
 }
 
@@ -39,11 +55,25 @@ impl CommonStyleBuilder {
     }
 
     pub fn build(self) -> Arc<CommonStyle> {
-        Arc::new(CommonStyle { empty_cell_icon: self.empty_cell_icon })
+        Arc::new(CommonStyle {
+            empty_cell_icon: self.empty_cell_icon,
+            previous_page_icon: self.previous_page_icon,
+            next_page_icon: self.next_page_icon,
+        })
     }
 
     pub fn empty_cell_icon(mut self, value: Cow<'static, str>) -> Self {
         self.empty_cell_icon = value;
         self
     }
+
+    pub fn previous_page_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.previous_page_icon = value;
+        self
+    }
+
+    pub fn next_page_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.next_page_icon = value;
+        self
+    }
 }
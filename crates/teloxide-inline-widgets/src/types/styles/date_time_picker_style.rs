@@ -0,0 +1,53 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`DateTimePicker`] widget style
+#[derive(Debug, Clone)]
+pub struct DateTimePickerStyle {
+    /// Format of the button with the selected date, see
+    /// [`chrono::format::strftime`]
+    pub date_format: Cow<'static, str>,
+}
+
+impl Default for DateTimePickerStyle {
+    fn default() -> Self {
+        Self { date_format: Cow::Borrowed("📅 %Y-%m-%d") }
+    }
+}
+
+impl DateTimePickerStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> DateTimePickerStyleBuilder {
+        DateTimePickerStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct DateTimePickerStyleBuilder {
+    /// Format of the button with the selected date, see
+    /// [`chrono::format::strftime`]
+    pub date_format: Cow<'static, str>,
+}
+
+impl Default for DateTimePickerStyleBuilder {
+    fn default() -> Self {
+        Self { date_format: Cow::Borrowed("📅 %Y-%m-%d") }
+    }
+}
+
+impl DateTimePickerStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<DateTimePickerStyle> {
+        Arc::new(DateTimePickerStyle { date_format: self.date_format })
+    }
+
+    pub fn date_format(mut self, value: Cow<'static, str>) -> Self {
+        self.date_format = value;
+        self
+    }
+}
@@ -0,0 +1,81 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Dropdown`] widget style
+#[derive(Debug, Clone)]
+pub struct DropdownStyle {
+    /// Icon of the button with the active item when the item list is hidden
+    pub collapsed_icon: Cow<'static, str>,
+    /// Icon of the button with the active item when the item list is displayed
+    pub expanded_icon: Cow<'static, str>,
+    /// Text of the button when there is no active item
+    pub placeholder: Cow<'static, str>,
+}
+
+impl Default for DropdownStyle {
+    fn default() -> Self {
+        Self {
+            collapsed_icon: Cow::Borrowed("🔽"),
+            expanded_icon: Cow::Borrowed("🔼"),
+            placeholder: Cow::Borrowed("—"),
+        }
+    }
+}
+
+impl DropdownStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> DropdownStyleBuilder {
+        DropdownStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct DropdownStyleBuilder {
+    /// Icon of the button with the active item when the item list is hidden
+    pub collapsed_icon: Cow<'static, str>,
+    /// Icon of the button with the active item when the item list is displayed
+    pub expanded_icon: Cow<'static, str>,
+    /// Text of the button when there is no active item
+    pub placeholder: Cow<'static, str>,
+}
+
+impl Default for DropdownStyleBuilder {
+    fn default() -> Self {
+        Self {
+            collapsed_icon: Cow::Borrowed("🔽"),
+            expanded_icon: Cow::Borrowed("🔼"),
+            placeholder: Cow::Borrowed("—"),
+        }
+    }
+}
+
+impl DropdownStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<DropdownStyle> {
+        Arc::new(DropdownStyle {
+            collapsed_icon: self.collapsed_icon,
+            expanded_icon: self.expanded_icon,
+            placeholder: self.placeholder,
+        })
+    }
+
+    pub fn collapsed_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.collapsed_icon = value;
+        self
+    }
+
+    pub fn expanded_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.expanded_icon = value;
+        self
+    }
+
+    pub fn placeholder(mut self, value: Cow<'static, str>) -> Self {
+        self.placeholder = value;
+        self
+    }
+}
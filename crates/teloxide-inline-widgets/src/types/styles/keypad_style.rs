@@ -0,0 +1,93 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Keypad`] widget style
+#[derive(Debug, Clone)]
+pub struct KeypadStyle {
+    /// Icon for `backspace` button
+    pub backspace_icon: Cow<'static, str>,
+    /// Icon for `confirm` button
+    pub confirm_icon: Cow<'static, str>,
+    /// Icon which replaces each typed digit when the input is masked
+    pub mask_icon: Cow<'static, str>,
+    /// Text of the header button when nothing is typed
+    pub empty_input_text: Cow<'static, str>,
+}
+
+impl Default for KeypadStyle {
+    fn default() -> Self {
+        Self {
+            backspace_icon: Cow::Borrowed("⌫"),
+            confirm_icon: Cow::Borrowed("✅"),
+            mask_icon: Cow::Borrowed("•"),
+            empty_input_text: Cow::Borrowed("—"),
+        }
+    }
+}
+
+impl KeypadStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> KeypadStyleBuilder {
+        KeypadStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct KeypadStyleBuilder {
+    /// Icon for `backspace` button
+    pub backspace_icon: Cow<'static, str>,
+    /// Icon for `confirm` button
+    pub confirm_icon: Cow<'static, str>,
+    /// Icon which replaces each typed digit when the input is masked
+    pub mask_icon: Cow<'static, str>,
+    /// Text of the header button when nothing is typed
+    pub empty_input_text: Cow<'static, str>,
+}
+
+impl Default for KeypadStyleBuilder {
+    fn default() -> Self {
+        Self {
+            backspace_icon: Cow::Borrowed("⌫"),
+            confirm_icon: Cow::Borrowed("✅"),
+            mask_icon: Cow::Borrowed("•"),
+            empty_input_text: Cow::Borrowed("—"),
+        }
+    }
+}
+
+impl KeypadStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<KeypadStyle> {
+        Arc::new(KeypadStyle {
+            backspace_icon: self.backspace_icon,
+            confirm_icon: self.confirm_icon,
+            mask_icon: self.mask_icon,
+            empty_input_text: self.empty_input_text,
+        })
+    }
+
+    pub fn backspace_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.backspace_icon = value;
+        self
+    }
+
+    pub fn confirm_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.confirm_icon = value;
+        self
+    }
+
+    pub fn mask_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.mask_icon = value;
+        self
+    }
+
+    pub fn empty_input_text(mut self, value: Cow<'static, str>) -> Self {
+        self.empty_input_text = value;
+        self
+    }
+}
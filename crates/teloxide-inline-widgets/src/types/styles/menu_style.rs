@@ -0,0 +1,83 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Menu`] widget style
+#[derive(Debug, Clone)]
+pub struct MenuStyle {
+    /// Text of the `back` button
+    pub back_text: Cow<'static, str>,
+    /// Format of the submenu buttons, `{label}` is replaced with the submenu
+    /// label
+    pub submenu_format: Cow<'static, str>,
+    /// Separator of the opened submenus labels in the breadcrumb
+    pub breadcrumb_separator: Cow<'static, str>,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            back_text: Cow::Borrowed("◀️ Back"),
+            submenu_format: Cow::Borrowed("{label} ›"),
+            breadcrumb_separator: Cow::Borrowed(" › "),
+        }
+    }
+}
+
+impl MenuStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> MenuStyleBuilder {
+        MenuStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct MenuStyleBuilder {
+    /// Text of the `back` button
+    pub back_text: Cow<'static, str>,
+    /// Format of the submenu buttons, `{label}` is replaced with the submenu
+    /// label
+    pub submenu_format: Cow<'static, str>,
+    /// Separator of the opened submenus labels in the breadcrumb
+    pub breadcrumb_separator: Cow<'static, str>,
+}
+
+impl Default for MenuStyleBuilder {
+    fn default() -> Self {
+        Self {
+            back_text: Cow::Borrowed("◀️ Back"),
+            submenu_format: Cow::Borrowed("{label} ›"),
+            breadcrumb_separator: Cow::Borrowed(" › "),
+        }
+    }
+}
+
+impl MenuStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<MenuStyle> {
+        Arc::new(MenuStyle {
+            back_text: self.back_text,
+            submenu_format: self.submenu_format,
+            breadcrumb_separator: self.breadcrumb_separator,
+        })
+    }
+
+    pub fn back_text(mut self, value: Cow<'static, str>) -> Self {
+        self.back_text = value;
+        self
+    }
+
+    pub fn submenu_format(mut self, value: Cow<'static, str>) -> Self {
+        self.submenu_format = value;
+        self
+    }
+
+    pub fn breadcrumb_separator(mut self, value: Cow<'static, str>) -> Self {
+        self.breadcrumb_separator = value;
+        self
+    }
+}
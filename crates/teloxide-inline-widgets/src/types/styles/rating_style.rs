@@ -0,0 +1,60 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Rating`] widget style
+#[derive(Debug, Clone)]
+pub struct RatingStyle {
+    /// Icon of the star within the rating
+    pub filled_icon: Cow<'static, str>,
+    /// Icon of the star beyond the rating
+    pub empty_icon: Cow<'static, str>,
+}
+
+impl Default for RatingStyle {
+    fn default() -> Self {
+        Self { filled_icon: Cow::Borrowed("⭐"), empty_icon: Cow::Borrowed("☆") }
+    }
+}
+
+impl RatingStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> RatingStyleBuilder {
+        RatingStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct RatingStyleBuilder {
+    /// Icon of the star within the rating
+    pub filled_icon: Cow<'static, str>,
+    /// Icon of the star beyond the rating
+    pub empty_icon: Cow<'static, str>,
+}
+
+impl Default for RatingStyleBuilder {
+    fn default() -> Self {
+        Self { filled_icon: Cow::Borrowed("⭐"), empty_icon: Cow::Borrowed("☆") }
+    }
+}
+
+impl RatingStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<RatingStyle> {
+        Arc::new(RatingStyle { filled_icon: self.filled_icon, empty_icon: self.empty_icon })
+    }
+
+    pub fn filled_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.filled_icon = value;
+        self
+    }
+
+    pub fn empty_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.empty_icon = value;
+        self
+    }
+}
@@ -0,0 +1,93 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`SortableList`] widget style
+#[derive(Debug, Clone)]
+pub struct SortableListStyle {
+    /// Icon of the selected item
+    pub selected_icon: Cow<'static, str>,
+    /// Icon of the `move up` button
+    pub move_up_icon: Cow<'static, str>,
+    /// Icon of the `move down` button
+    pub move_down_icon: Cow<'static, str>,
+    /// Icon of the `move to top` button
+    pub move_to_top_icon: Cow<'static, str>,
+}
+
+impl Default for SortableListStyle {
+    fn default() -> Self {
+        Self {
+            selected_icon: Cow::Borrowed("👉"),
+            move_up_icon: Cow::Borrowed("⬆️"),
+            move_down_icon: Cow::Borrowed("⬇️"),
+            move_to_top_icon: Cow::Borrowed("⏫"),
+        }
+    }
+}
+
+impl SortableListStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> SortableListStyleBuilder {
+        SortableListStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct SortableListStyleBuilder {
+    /// Icon of the selected item
+    pub selected_icon: Cow<'static, str>,
+    /// Icon of the `move up` button
+    pub move_up_icon: Cow<'static, str>,
+    /// Icon of the `move down` button
+    pub move_down_icon: Cow<'static, str>,
+    /// Icon of the `move to top` button
+    pub move_to_top_icon: Cow<'static, str>,
+}
+
+impl Default for SortableListStyleBuilder {
+    fn default() -> Self {
+        Self {
+            selected_icon: Cow::Borrowed("👉"),
+            move_up_icon: Cow::Borrowed("⬆️"),
+            move_down_icon: Cow::Borrowed("⬇️"),
+            move_to_top_icon: Cow::Borrowed("⏫"),
+        }
+    }
+}
+
+impl SortableListStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<SortableListStyle> {
+        Arc::new(SortableListStyle {
+            selected_icon: self.selected_icon,
+            move_up_icon: self.move_up_icon,
+            move_down_icon: self.move_down_icon,
+            move_to_top_icon: self.move_to_top_icon,
+        })
+    }
+
+    pub fn selected_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.selected_icon = value;
+        self
+    }
+
+    pub fn move_up_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.move_up_icon = value;
+        self
+    }
+
+    pub fn move_down_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.move_down_icon = value;
+        self
+    }
+
+    pub fn move_to_top_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.move_to_top_icon = value;
+        self
+    }
+}
@@ -0,0 +1,93 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Stepper`] widget style
+#[derive(Debug, Clone)]
+pub struct StepperStyle {
+    /// Icon for `increase` button
+    pub increase_icon: Cow<'static, str>,
+    /// Icon for `decrease` button
+    pub decrease_icon: Cow<'static, str>,
+    /// Icon for `big increase` button
+    pub big_increase_icon: Cow<'static, str>,
+    /// Icon for `big decrease` button
+    pub big_decrease_icon: Cow<'static, str>,
+}
+
+impl Default for StepperStyle {
+    fn default() -> Self {
+        Self {
+            increase_icon: Cow::Borrowed("➕"),
+            decrease_icon: Cow::Borrowed("➖"),
+            big_increase_icon: Cow::Borrowed("⏩"),
+            big_decrease_icon: Cow::Borrowed("⏪"),
+        }
+    }
+}
+
+impl StepperStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> StepperStyleBuilder {
+        StepperStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct StepperStyleBuilder {
+    /// Icon for `increase` button
+    pub increase_icon: Cow<'static, str>,
+    /// Icon for `decrease` button
+    pub decrease_icon: Cow<'static, str>,
+    /// Icon for `big increase` button
+    pub big_increase_icon: Cow<'static, str>,
+    /// Icon for `big decrease` button
+    pub big_decrease_icon: Cow<'static, str>,
+}
+
+impl Default for StepperStyleBuilder {
+    fn default() -> Self {
+        Self {
+            increase_icon: Cow::Borrowed("➕"),
+            decrease_icon: Cow::Borrowed("➖"),
+            big_increase_icon: Cow::Borrowed("⏩"),
+            big_decrease_icon: Cow::Borrowed("⏪"),
+        }
+    }
+}
+
+impl StepperStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<StepperStyle> {
+        Arc::new(StepperStyle {
+            increase_icon: self.increase_icon,
+            decrease_icon: self.decrease_icon,
+            big_increase_icon: self.big_increase_icon,
+            big_decrease_icon: self.big_decrease_icon,
+        })
+    }
+
+    pub fn increase_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.increase_icon = value;
+        self
+    }
+
+    pub fn decrease_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.decrease_icon = value;
+        self
+    }
+
+    pub fn big_increase_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.big_increase_icon = value;
+        self
+    }
+
+    pub fn big_decrease_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.big_decrease_icon = value;
+        self
+    }
+}
@@ -0,0 +1,63 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Table`] widget style
+#[derive(Debug, Clone)]
+pub struct TableStyle {
+    /// Icon of the header of the column sorted in the ascending order
+    pub ascending_icon: Cow<'static, str>,
+    /// Icon of the header of the column sorted in the descending order
+    pub descending_icon: Cow<'static, str>,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self { ascending_icon: Cow::Borrowed("🔼"), descending_icon: Cow::Borrowed("🔽") }
+    }
+}
+
+impl TableStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> TableStyleBuilder {
+        TableStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct TableStyleBuilder {
+    /// Icon of the header of the column sorted in the ascending order
+    pub ascending_icon: Cow<'static, str>,
+    /// Icon of the header of the column sorted in the descending order
+    pub descending_icon: Cow<'static, str>,
+}
+
+impl Default for TableStyleBuilder {
+    fn default() -> Self {
+        Self { ascending_icon: Cow::Borrowed("🔼"), descending_icon: Cow::Borrowed("🔽") }
+    }
+}
+
+impl TableStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<TableStyle> {
+        Arc::new(TableStyle {
+            ascending_icon: self.ascending_icon,
+            descending_icon: self.descending_icon,
+        })
+    }
+
+    pub fn ascending_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.ascending_icon = value;
+        self
+    }
+
+    pub fn descending_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.descending_icon = value;
+        self
+    }
+}
@@ -0,0 +1,51 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Tabs`] widget style
+#[derive(Debug, Clone)]
+pub struct TabsStyle {
+    /// Format of the active tab, `{label}` is replaced with the tab label
+    pub active_tab_format: Cow<'static, str>,
+}
+
+impl Default for TabsStyle {
+    fn default() -> Self {
+        Self { active_tab_format: Cow::Borrowed("• {label} •") }
+    }
+}
+
+impl TabsStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> TabsStyleBuilder {
+        TabsStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct TabsStyleBuilder {
+    /// Format of the active tab, `{label}` is replaced with the tab label
+    pub active_tab_format: Cow<'static, str>,
+}
+
+impl Default for TabsStyleBuilder {
+    fn default() -> Self {
+        Self { active_tab_format: Cow::Borrowed("• {label} •") }
+    }
+}
+
+impl TabsStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<TabsStyle> {
+        Arc::new(TabsStyle { active_tab_format: self.active_tab_format })
+    }
+
+    pub fn active_tab_format(mut self, value: Cow<'static, str>) -> Self {
+        self.active_tab_format = value;
+        self
+    }
+}
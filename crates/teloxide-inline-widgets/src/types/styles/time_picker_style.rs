@@ -0,0 +1,117 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`TimePicker`] widget style
+#[derive(Debug, Clone)]
+pub struct TimePickerStyle {
+    /// Icon for `increase hour/minute` buttons
+    pub increase_icon: Cow<'static, str>,
+    /// Icon for `decrease hour/minute` buttons
+    pub decrease_icon: Cow<'static, str>,
+    /// Text between the hours and the minutes
+    pub separator: Cow<'static, str>,
+    /// Text of the `AM` button in the 12-hour format
+    pub am_text: Cow<'static, str>,
+    /// Text of the `PM` button in the 12-hour format
+    pub pm_text: Cow<'static, str>,
+    /// Text of the `confirm` button
+    pub confirm_text: Cow<'static, str>,
+}
+
+impl Default for TimePickerStyle {
+    fn default() -> Self {
+        Self {
+            increase_icon: Cow::Borrowed("🔼"),
+            decrease_icon: Cow::Borrowed("🔽"),
+            separator: Cow::Borrowed(":"),
+            am_text: Cow::Borrowed("AM"),
+            pm_text: Cow::Borrowed("PM"),
+            confirm_text: Cow::Borrowed("Confirm"),
+        }
+    }
+}
+
+impl TimePickerStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> TimePickerStyleBuilder {
+        TimePickerStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct TimePickerStyleBuilder {
+    /// Icon for `increase hour/minute` buttons
+    pub increase_icon: Cow<'static, str>,
+    /// Icon for `decrease hour/minute` buttons
+    pub decrease_icon: Cow<'static, str>,
+    /// Text between the hours and the minutes
+    pub separator: Cow<'static, str>,
+    /// Text of the `AM` button in the 12-hour format
+    pub am_text: Cow<'static, str>,
+    /// Text of the `PM` button in the 12-hour format
+    pub pm_text: Cow<'static, str>,
+    /// Text of the `confirm` button
+    pub confirm_text: Cow<'static, str>,
+}
+
+impl Default for TimePickerStyleBuilder {
+    fn default() -> Self {
+        Self {
+            increase_icon: Cow::Borrowed("🔼"),
+            decrease_icon: Cow::Borrowed("🔽"),
+            separator: Cow::Borrowed(":"),
+            am_text: Cow::Borrowed("AM"),
+            pm_text: Cow::Borrowed("PM"),
+            confirm_text: Cow::Borrowed("Confirm"),
+        }
+    }
+}
+
+impl TimePickerStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<TimePickerStyle> {
+        Arc::new(TimePickerStyle {
+            increase_icon: self.increase_icon,
+            decrease_icon: self.decrease_icon,
+            separator: self.separator,
+            am_text: self.am_text,
+            pm_text: self.pm_text,
+            confirm_text: self.confirm_text,
+        })
+    }
+
+    pub fn increase_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.increase_icon = value;
+        self
+    }
+
+    pub fn decrease_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.decrease_icon = value;
+        self
+    }
+
+    pub fn separator(mut self, value: Cow<'static, str>) -> Self {
+        self.separator = value;
+        self
+    }
+
+    pub fn am_text(mut self, value: Cow<'static, str>) -> Self {
+        self.am_text = value;
+        self
+    }
+
+    pub fn pm_text(mut self, value: Cow<'static, str>) -> Self {
+        self.pm_text = value;
+        self
+    }
+
+    pub fn confirm_text(mut self, value: Cow<'static, str>) -> Self {
+        self.confirm_text = value;
+        self
+    }
+}
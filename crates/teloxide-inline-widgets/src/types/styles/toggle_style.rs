@@ -0,0 +1,60 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Toggle`] widget style
+#[derive(Debug, Clone)]
+pub struct ToggleStyle {
+    /// Icon of the switched on toggle
+    pub on_icon: Cow<'static, str>,
+    /// Icon of the switched off toggle
+    pub off_icon: Cow<'static, str>,
+}
+
+impl Default for ToggleStyle {
+    fn default() -> Self {
+        Self { on_icon: Cow::Borrowed("✅"), off_icon: Cow::Borrowed("⬜") }
+    }
+}
+
+impl ToggleStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> ToggleStyleBuilder {
+        ToggleStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct ToggleStyleBuilder {
+    /// Icon of the switched on toggle
+    pub on_icon: Cow<'static, str>,
+    /// Icon of the switched off toggle
+    pub off_icon: Cow<'static, str>,
+}
+
+impl Default for ToggleStyleBuilder {
+    fn default() -> Self {
+        Self { on_icon: Cow::Borrowed("✅"), off_icon: Cow::Borrowed("⬜") }
+    }
+}
+
+impl ToggleStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<ToggleStyle> {
+        Arc::new(ToggleStyle { on_icon: self.on_icon, off_icon: self.off_icon })
+    }
+
+    pub fn on_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.on_icon = value;
+        self
+    }
+
+    pub fn off_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.off_icon = value;
+        self
+    }
+}
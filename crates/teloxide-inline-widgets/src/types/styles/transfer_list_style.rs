@@ -0,0 +1,63 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`TransferList`] widget style
+#[derive(Debug, Clone)]
+pub struct TransferListStyle {
+    /// Header of the available items column
+    pub available_title: Cow<'static, str>,
+    /// Header of the chosen items column
+    pub chosen_title: Cow<'static, str>,
+}
+
+impl Default for TransferListStyle {
+    fn default() -> Self {
+        Self { available_title: Cow::Borrowed("Available"), chosen_title: Cow::Borrowed("Chosen") }
+    }
+}
+
+impl TransferListStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> TransferListStyleBuilder {
+        TransferListStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct TransferListStyleBuilder {
+    /// Header of the available items column
+    pub available_title: Cow<'static, str>,
+    /// Header of the chosen items column
+    pub chosen_title: Cow<'static, str>,
+}
+
+impl Default for TransferListStyleBuilder {
+    fn default() -> Self {
+        Self { available_title: Cow::Borrowed("Available"), chosen_title: Cow::Borrowed("Chosen") }
+    }
+}
+
+impl TransferListStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<TransferListStyle> {
+        Arc::new(TransferListStyle {
+            available_title: self.available_title,
+            chosen_title: self.chosen_title,
+        })
+    }
+
+    pub fn available_title(mut self, value: Cow<'static, str>) -> Self {
+        self.available_title = value;
+        self
+    }
+
+    pub fn chosen_title(mut self, value: Cow<'static, str>) -> Self {
+        self.chosen_title = value;
+        self
+    }
+}
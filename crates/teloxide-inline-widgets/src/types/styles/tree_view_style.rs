@@ -0,0 +1,105 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`TreeView`] widget style
+#[derive(Debug, Clone)]
+pub struct TreeViewStyle {
+    /// Icon of the expanded branch
+    pub expanded_icon: Cow<'static, str>,
+    /// Icon of the collapsed branch
+    pub collapsed_icon: Cow<'static, str>,
+    /// Icon of the selected leaf
+    pub selected_icon: Cow<'static, str>,
+    /// Icon of the unselected leaf
+    pub unselected_icon: Cow<'static, str>,
+    /// Is repeated before the node label according to its depth
+    pub indent: Cow<'static, str>,
+}
+
+impl Default for TreeViewStyle {
+    fn default() -> Self {
+        Self {
+            expanded_icon: Cow::Borrowed("▾"),
+            collapsed_icon: Cow::Borrowed("▸"),
+            selected_icon: Cow::Borrowed("✅"),
+            unselected_icon: Cow::Borrowed("▫️"),
+            indent: Cow::Borrowed("· "),
+        }
+    }
+}
+
+impl TreeViewStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> TreeViewStyleBuilder {
+        TreeViewStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct TreeViewStyleBuilder {
+    /// Icon of the expanded branch
+    pub expanded_icon: Cow<'static, str>,
+    /// Icon of the collapsed branch
+    pub collapsed_icon: Cow<'static, str>,
+    /// Icon of the selected leaf
+    pub selected_icon: Cow<'static, str>,
+    /// Icon of the unselected leaf
+    pub unselected_icon: Cow<'static, str>,
+    /// Is repeated before the node label according to its depth
+    pub indent: Cow<'static, str>,
+}
+
+impl Default for TreeViewStyleBuilder {
+    fn default() -> Self {
+        Self {
+            expanded_icon: Cow::Borrowed("▾"),
+            collapsed_icon: Cow::Borrowed("▸"),
+            selected_icon: Cow::Borrowed("✅"),
+            unselected_icon: Cow::Borrowed("▫️"),
+            indent: Cow::Borrowed("· "),
+        }
+    }
+}
+
+impl TreeViewStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<TreeViewStyle> {
+        Arc::new(TreeViewStyle {
+            expanded_icon: self.expanded_icon,
+            collapsed_icon: self.collapsed_icon,
+            selected_icon: self.selected_icon,
+            unselected_icon: self.unselected_icon,
+            indent: self.indent,
+        })
+    }
+
+    pub fn expanded_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.expanded_icon = value;
+        self
+    }
+
+    pub fn collapsed_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.collapsed_icon = value;
+        self
+    }
+
+    pub fn selected_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.selected_icon = value;
+        self
+    }
+
+    pub fn unselected_icon(mut self, value: Cow<'static, str>) -> Self {
+        self.unselected_icon = value;
+        self
+    }
+
+    pub fn indent(mut self, value: Cow<'static, str>) -> Self {
+        self.indent = value;
+        self
+    }
+}
@@ -1,11 +1,31 @@
 use std::sync::Arc;
 
-use crate::types::{CalendarStyle, CheckboxListStyle, CommonStyle, RadioListStyle};
+use crate::types::{
+    ButtonStyle, CalendarStyle, CheckboxListStyle, CommonStyle, DateTimePickerStyle, DropdownStyle,
+    KeypadStyle, MenuStyle, RadioListStyle, RatingStyle, SortableListStyle, StepperStyle,
+    TableStyle, TabsStyle, TimePickerStyle, ToggleStyle, TransferListStyle, TreeViewStyle,
+    WizardStyle,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct WidgetStyles {
     pub radio_list_style: Arc<RadioListStyle>,
     pub checkbox_list_style: Arc<CheckboxListStyle>,
     pub calendar_style: Arc<CalendarStyle>,
+    pub time_picker_style: Arc<TimePickerStyle>,
+    pub date_time_picker_style: Arc<DateTimePickerStyle>,
+    pub stepper_style: Arc<StepperStyle>,
+    pub toggle_style: Arc<ToggleStyle>,
+    pub keypad_style: Arc<KeypadStyle>,
+    pub rating_style: Arc<RatingStyle>,
+    pub button_style: Arc<ButtonStyle>,
+    pub tabs_style: Arc<TabsStyle>,
+    pub wizard_style: Arc<WizardStyle>,
+    pub menu_style: Arc<MenuStyle>,
+    pub dropdown_style: Arc<DropdownStyle>,
+    pub sortable_list_style: Arc<SortableListStyle>,
+    pub transfer_list_style: Arc<TransferListStyle>,
+    pub tree_view_style: Arc<TreeViewStyle>,
+    pub table_style: Arc<TableStyle>,
     pub common_style: Arc<CommonStyle>,
 }
@@ -0,0 +1,97 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// [`Wizard`] widget style
+#[derive(Debug, Clone)]
+pub struct WizardStyle {
+    /// Text of the `back` button
+    pub back_text: Cow<'static, str>,
+    /// Text of the `next` button
+    pub next_text: Cow<'static, str>,
+    /// Text of the `finish` button, which replaces the `next` button on the
+    /// last step
+    pub finish_text: Cow<'static, str>,
+    /// Format of the progress indicator, `{step}` and `{steps}` are replaced
+    /// with the current step number and the number of the steps
+    pub progress_format: Cow<'static, str>,
+}
+
+impl Default for WizardStyle {
+    fn default() -> Self {
+        Self {
+            back_text: Cow::Borrowed("◀️ Back"),
+            next_text: Cow::Borrowed("Next ▶️"),
+            finish_text: Cow::Borrowed("✅ Finish"),
+            progress_format: Cow::Borrowed("{step}/{steps}"),
+        }
+    }
+}
+
+impl WizardStyle {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn builder() -> WizardStyleBuilder {
+        WizardStyleBuilder::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct WizardStyleBuilder {
+    /// Text of the `back` button
+    pub back_text: Cow<'static, str>,
+    /// Text of the `next` button
+    pub next_text: Cow<'static, str>,
+    /// Text of the `finish` button, which replaces the `next` button on the
+    /// last step
+    pub finish_text: Cow<'static, str>,
+    /// Format of the progress indicator, `{step}` and `{steps}` are replaced
+    /// with the current step number and the number of the steps
+    pub progress_format: Cow<'static, str>,
+}
+
+impl Default for WizardStyleBuilder {
+    fn default() -> Self {
+        Self {
+            back_text: Cow::Borrowed("◀️ Back"),
+            next_text: Cow::Borrowed("Next ▶️"),
+            finish_text: Cow::Borrowed("✅ Finish"),
+            progress_format: Cow::Borrowed("{step}/{steps}"),
+        }
+    }
+}
+
+impl WizardStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Arc<WizardStyle> {
+        Arc::new(WizardStyle {
+            back_text: self.back_text,
+            next_text: self.next_text,
+            finish_text: self.finish_text,
+            progress_format: self.progress_format,
+        })
+    }
+
+    pub fn back_text(mut self, value: Cow<'static, str>) -> Self {
+        self.back_text = value;
+        self
+    }
+
+    pub fn next_text(mut self, value: Cow<'static, str>) -> Self {
+        self.next_text = value;
+        self
+    }
+
+    pub fn finish_text(mut self, value: Cow<'static, str>) -> Self {
+        self.finish_text = value;
+        self
+    }
+
+    pub fn progress_format(mut self, value: Cow<'static, str>) -> Self {
+        self.progress_format = value;
+        self
+    }
+}
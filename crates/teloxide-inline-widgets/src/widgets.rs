@@ -1,6 +1,25 @@
 mod button;
 mod calendar;
 mod checkbox_list;
+mod date_time_picker;
+mod dropdown;
+mod keypad;
+mod menu;
+mod pagination;
 mod radio_list;
+mod rating;
+mod sortable_list;
+mod stepper;
+mod table;
+mod tabs;
+mod time_picker;
+mod toggle;
+mod transfer_list;
+mod tree_view;
+mod wizard;
 
-pub use self::{button::*, calendar::*, checkbox_list::*, radio_list::*};
+pub use self::{
+    button::*, calendar::*, checkbox_list::*, date_time_picker::*, dropdown::*, keypad::*, menu::*,
+    radio_list::*, rating::*, sortable_list::*, stepper::*, table::*, tabs::*, time_picker::*,
+    toggle::*, transfer_list::*, tree_view::*, wizard::*,
+};
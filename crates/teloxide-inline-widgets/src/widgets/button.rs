@@ -1,19 +1,114 @@
 use serde::{Deserialize, Serialize};
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
 
-use crate::{traits::GetSize, types::Size};
+use crate::{
+    handlers::{redraw_widget, update_widget},
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
 
 /// Single inline-keyboard `callback query` button
+///
+/// Since the [`Button`] holds the private confirmation state, it can't be
+/// created with the `Button { label }` struct literal anymore, use
+/// [`Button::new`] instead
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Button {
     /// Text that is displayed within a button
     pub label: String,
+    /// Whether the click is waiting for the confirmation
+    #[serde(default)]
+    is_confirming: bool,
 }
 
 impl Button {
     /// Creates new [`Button`] instance with provided label
     pub fn new(label: &str) -> Self {
-        Self { label: label.to_owned() }
+        Self { label: label.to_owned(), is_confirming: false }
+    }
+
+    /// Returns `true` if the click is waiting for the confirmation, so the
+    /// `Yes/No` buttons are displayed instead of the widget
+    pub fn is_confirming(&self) -> bool {
+        self.is_confirming
+    }
+
+    /// Sets whether the click is waiting for the confirmation
+    pub fn set_confirming(&mut self, is_confirming: bool) {
+        self.is_confirming = is_confirming;
+    }
+
+    /// [`dptree`]-schema for the [`Button`] widget which asks for the
+    /// confirmation before the `click` handler is invoked
+    ///
+    /// The button is retrieved from the `C` widget, which contains it, by the
+    /// `button` accessor. The click on the button swaps the keyboard to the
+    /// `Yes/No` buttons, then the `click` handler is invoked only on `Yes`
+    /// after the widget is redrawn, so it's supposed to answer the callback
+    /// query. The widget is just redrawn on `No`. The `Yes/No` clicks are
+    /// ignored if the button isn't waiting for the confirmation, e.g. the
+    /// stale or forged ones
+    pub fn confirm_schema<W, C>(
+        parameters: &'static ButtonConfirmParameters,
+        button: fn(&mut C) -> &mut Self,
+        click: UpdateHandler<W::Err>,
+    ) -> UpdateHandler<W::Err>
+    where
+        C: 'static,
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<C>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let set_confirming = move |is_confirming: bool| {
+            move |mut widget: W| {
+                button(widget.get_widget()).set_confirming(is_confirming);
+                widget
+            }
+        };
+        let data_filter = |data: &'static str| {
+            dptree::filter(move |cq: CallbackQuery| cq.data.as_deref() == Some(data))
+        };
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .branch(
+                dptree::filter(move |mut widget: W, cq: CallbackQuery| {
+                    let data = cq.data.as_deref();
+                    (data == Some(parameters.yes_data) || data == Some(parameters.no_data))
+                        && !button(widget.get_widget()).is_confirming()
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the stale confirmation button");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                data_filter(parameters.data)
+                    .map(set_confirming(true))
+                    .chain(update_widget::<W>())
+                    .endpoint(|| async { Ok(()) }),
+            )
+            .branch(
+                data_filter(parameters.yes_data)
+                    .map(set_confirming(false))
+                    .chain(redraw_widget::<W>())
+                    .chain(click),
+            )
+            .branch(
+                data_filter(parameters.no_data)
+                    .map(set_confirming(false))
+                    .chain(update_widget::<W>())
+                    .endpoint(|| async { Ok(()) }),
+            )
     }
 
     /// Creates the [`InlineKeyboardMarkup`] for a [`Button`] widget with
@@ -23,6 +118,37 @@ impl Button {
     pub fn inline_keyboard_markup(&self, data: &'static str) -> InlineKeyboardMarkup {
         InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(&self.label, data)]])
     }
+
+    /// Returns the size of the confirmation keyboard, which replaces the whole
+    /// widget while the click is waiting for the confirmation
+    pub fn confirmation_size(&self) -> Size {
+        Size { rows: 2, columns: 2 }
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] with the confirmation question and
+    /// the `Yes/No` buttons, which replaces the whole widget while the click
+    /// is waiting for the confirmation
+    ///
+    /// It's not supposed to be used directly
+    pub fn confirmation_inline_keyboard_markup(
+        &self,
+        parameters: &ButtonConfirmParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        InlineKeyboardMarkup::new(vec![
+            vec![InlineKeyboardButton::callback(parameters.question, parameters.noop_data)],
+            vec![
+                InlineKeyboardButton::callback(
+                    styles.button_style.yes_text.clone(),
+                    parameters.yes_data,
+                ),
+                InlineKeyboardButton::callback(
+                    styles.button_style.no_text.clone(),
+                    parameters.no_data,
+                ),
+            ],
+        ])
+    }
 }
 
 impl GetSize for Button {
@@ -30,3 +156,88 @@ impl GetSize for Button {
         Size { rows: 1, columns: 1 }
     }
 }
+
+pub struct ButtonConfirmParameters {
+    /// CallbackQuery data to be sent when the button is clicked
+    pub data: &'static str,
+    /// Text of the confirmation question
+    pub question: &'static str,
+    /// CallbackQuery data to be sent when the click is confirmed
+    pub yes_data: &'static str,
+    /// CallbackQuery data to be sent when the click is cancelled
+    pub no_data: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<ButtonWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct ButtonWidget {
+        #[button(data = "delete", click = delete, confirm = "Are you sure?")]
+        delete_button: Button,
+    }
+
+    async fn delete(
+        bot: Bot,
+        (chat_id, _, cq_id): (ChatId, MessageId, String),
+    ) -> Result<(), Error> {
+        bot.answer_callback_query(cq_id).await?;
+        bot.send_message(chat_id, "Deleted").await?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn confirmation() {
+        let mut button = Button::new("Delete");
+        assert_eq!(button.size(), Size::new(1, 1));
+        button.set_confirming(true);
+        // The confirmation keyboard replaces the whole widget, not only the button
+        assert_eq!(button.size(), Size::new(1, 1));
+        assert_eq!(button.confirmation_size(), Size::new(2, 2));
+
+        let parameters = ButtonConfirmParameters {
+            data: "delete",
+            question: "Are you sure?",
+            yes_data: "delete_y",
+            no_data: "delete_n",
+            noop_data: "noop",
+        };
+        let keyboard = button
+            .confirmation_inline_keyboard_markup(&parameters, &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "Are you sure?");
+        assert_eq!(keyboard[1].len(), 2);
+        assert_eq!(keyboard.len() as u8, button.confirmation_size().rows);
+    }
+
+    #[tokio::test]
+    async fn stale_confirmation() {
+        let widget = ButtonWidget { delete_button: Button::new("Delete") };
+
+        // The button isn't waiting for the confirmation, e.g. `Yes` was already
+        // clicked or `No` was clicked before
+        assert!(dispatch(widget.clone(), "delete_y").await.is_ignored());
+        assert!(dispatch(widget.clone(), "delete_n").await.is_ignored());
+
+        let dispatched = dispatch(widget, "delete").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        let widget = dispatched.widget.unwrap();
+        assert!(widget.delete_button.is_confirming());
+
+        let dispatched = dispatch(widget, "delete_y").await;
+        assert_eq!(
+            dispatched.methods(),
+            ["EditMessageReplyMarkup", "AnswerCallbackQuery", "SendMessage"]
+        );
+        assert!(!dispatched.widget.unwrap().delete_button.is_confirming());
+    }
+}
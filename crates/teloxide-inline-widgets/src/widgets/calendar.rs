@@ -1,29 +1,146 @@
-use std::iter::repeat;
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    iter::{repeat, successors},
+};
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
 
 use crate::{
-    traits::GetSize,
+    handlers::{redraw_widget, update_widget},
+    traits::{GetSize, InlineWidget, WidgetContainer},
     types::{Size, WidgetStyles},
 };
 
 // TODO put behind the `calendar` feature-flag
 
+/// Number of years displayed in the [`CalendarView::Years`]
+const YEARS_PAGE_SIZE: u32 = 12;
+/// Number of columns in the [`CalendarView::Months`] and the
+/// [`CalendarView::Years`]
+const GRID_COLUMNS: usize = 4;
+
+/// The view of the [`Calendar`] widget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CalendarView {
+    /// The days of the selected month
+    #[default]
+    Days,
+    /// The months of the selected year, the clicked month is displayed in the
+    /// [`CalendarView::Days`]
+    Months,
+    /// The page of years, the clicked year is displayed in the
+    /// [`CalendarView::Months`]
+    Years,
+}
+
+/// The way the clicked days are selected in the [`Calendar`] widget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CalendarSelectionMode {
+    /// The clicked day becomes the selected one
+    #[default]
+    Single,
+    /// The first click sets the start of the range, the second one sets the
+    /// end of the range
+    Range,
+    /// Each click toggles the selection of the day, like in the
+    /// [`CheckboxList`](crate::CheckboxList)
+    Multiple,
+}
+
+/// Navigation action performed by a [`Calendar`] button. Used as a unique type
+/// in the [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy)]
+enum CalendarAction {
+    PreviousYear,
+    NextYear,
+    PreviousMonth,
+    NextMonth,
+    DaysView,
+    MonthsView,
+    YearsView,
+    PreviousYearsPage,
+    NextYearsPage,
+    SelectMonth(u32),
+    SelectYear(u32),
+}
+
+impl CalendarAction {
+    fn from_data(data: &str, parameters: &CalendarSchemaParameters) -> Option<Self> {
+        let action = match data {
+            _ if data == parameters.previous_year_data => Self::PreviousYear,
+            _ if data == parameters.next_year_data => Self::NextYear,
+            _ if data == parameters.previous_month_data => Self::PreviousMonth,
+            _ if data == parameters.next_month_data => Self::NextMonth,
+            _ if data == parameters.days_view_data => Self::DaysView,
+            _ if data == parameters.months_view_data => Self::MonthsView,
+            _ if data == parameters.years_view_data => Self::YearsView,
+            _ if data == parameters.previous_years_page_data => Self::PreviousYearsPage,
+            _ if data == parameters.next_years_page_data => Self::NextYearsPage,
+            _ => {
+                if let Some(month) = data.strip_prefix(parameters.month_prefix) {
+                    Self::SelectMonth(month.parse().ok()?)
+                } else {
+                    Self::SelectYear(data.strip_prefix(parameters.year_prefix)?.parse().ok()?)
+                }
+            }
+        };
+        Some(action)
+    }
+}
+
 /// Calendar widget
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+///
+/// The days beyond the `min_date` and `max_date` bounds as well as the
+/// disabled dates can't be clicked. The clicked days are selected according to
+/// the [`CalendarSelectionMode`], so the widget can be used as a date picker
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Calendar {
     /// Currently selected year
     year: u32,
     /// Currently selected month, 1-based
     month: u32,
+    /// The earliest date available
+    #[serde(default)]
+    min_date: Option<NaiveDate>,
+    /// The latest date available
+    #[serde(default)]
+    max_date: Option<NaiveDate>,
+    /// Dates that can't be clicked
+    #[serde(default)]
+    disabled_dates: BTreeSet<NaiveDate>,
+    /// The way the clicked days are selected
+    #[serde(default)]
+    selection_mode: CalendarSelectionMode,
+    /// The last clicked date or the start of the range
+    #[serde(default)]
+    selected_date: Option<NaiveDate>,
+    /// The end of the range, is set only in the
+    /// [`CalendarSelectionMode::Range`]
+    #[serde(default)]
+    range_end: Option<NaiveDate>,
+    /// Dates selected in the [`CalendarSelectionMode::Multiple`]
+    #[serde(default)]
+    selected_dates: BTreeSet<NaiveDate>,
+    /// The day of the week displayed in the first column
+    #[serde(default = "default_first_weekday")]
+    first_weekday: Weekday,
+    /// Current view of the widget
+    #[serde(default)]
+    view: CalendarView,
 }
 
 impl Default for Calendar {
     fn default() -> Self {
         let now = Local::now();
-        Self { year: now.year() as u32, month: now.month() }
+        Self::with_ym(now.year() as u32, now.month())
     }
 }
 
@@ -37,7 +154,241 @@ impl Calendar {
 
     /// Creates the [`Calendar`] widget with the selected `year` and `month`
     pub fn with_ym(year: u32, month: u32) -> Self {
-        Self { year, month }
+        Self {
+            year,
+            month,
+            min_date: None,
+            max_date: None,
+            disabled_dates: BTreeSet::new(),
+            selection_mode: CalendarSelectionMode::Single,
+            selected_date: None,
+            range_end: None,
+            selected_dates: BTreeSet::new(),
+            first_weekday: default_first_weekday(),
+            view: CalendarView::Days,
+        }
+    }
+
+    /// Returns the current view of the widget
+    pub fn view(&self) -> CalendarView {
+        self.view
+    }
+
+    /// Sets the current view of the widget
+    ///
+    /// The [`CalendarView::Days`] points the [`Calendar`] to the nearest month
+    /// within the date bounds, since the pages of years may go beyond them
+    pub fn set_view(&mut self, view: CalendarView) {
+        if view == CalendarView::Days {
+            self.clamp_ym_to_bounds();
+        }
+        self.view = view;
+    }
+
+    /// Returns the day of the week displayed in the first column
+    pub fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+
+    /// Sets the day of the week displayed in the first column, [`Weekday::Mon`]
+    /// is used by default
+    pub fn set_first_weekday(&mut self, first_weekday: Weekday) {
+        self.first_weekday = first_weekday;
+    }
+
+    /// Returns the way the clicked days are selected
+    pub fn selection_mode(&self) -> CalendarSelectionMode {
+        self.selection_mode
+    }
+
+    /// Sets the way the clicked days are selected, resets the selection
+    pub fn set_selection_mode(&mut self, selection_mode: CalendarSelectionMode) {
+        self.selection_mode = selection_mode;
+        self.selected_date = None;
+        self.range_end = None;
+        self.selected_dates.clear();
+    }
+
+    /// Returns the selected date
+    ///
+    /// In the [`CalendarSelectionMode::Range`] it's the start of the range
+    pub fn selected_date(&self) -> Option<NaiveDate> {
+        self.selected_date
+    }
+
+    /// Sets the selected date, doesn't change the displayed month
+    pub fn set_selected_date(&mut self, selected_date: Option<NaiveDate>) {
+        self.selected_date = selected_date;
+        self.range_end = None;
+    }
+
+    /// Returns the `(start, end)` dates of the range if both of them are
+    /// selected
+    pub fn selected_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.selected_date.zip(self.range_end)
+    }
+
+    /// Sets the selected range, the dates are swapped if the `end` precedes
+    /// the `start`
+    ///
+    /// The range isn't checked for the disabled dates, use
+    /// [`Calendar::range_contains_disabled_dates`] if it's needed
+    pub fn set_selected_range(&mut self, start: NaiveDate, end: NaiveDate) {
+        self.selected_date = Some(start.min(end));
+        self.range_end = Some(start.max(end));
+    }
+
+    /// Returns the dates selected in the [`CalendarSelectionMode::Multiple`]
+    pub fn selected_dates(&self) -> &BTreeSet<NaiveDate> {
+        &self.selected_dates
+    }
+
+    /// Selects the date if it's not selected, unselects it otherwise
+    ///
+    /// Is used in the [`CalendarSelectionMode::Multiple`]
+    pub fn toggle_date(&mut self, date: NaiveDate) {
+        if !self.selected_dates.remove(&date) {
+            self.selected_dates.insert(date);
+        }
+    }
+
+    /// Selects the clicked date according to the [`CalendarSelectionMode`]
+    ///
+    /// The date is ignored if it's disabled, see
+    /// [`Calendar::is_date_disabled`].
+    /// In the [`CalendarSelectionMode::Range`] the end of the range is also
+    /// ignored if the range contains the disabled dates
+    pub fn select_date(&mut self, date: NaiveDate) {
+        if self.is_date_disabled(date) {
+            return;
+        }
+
+        match (self.selection_mode, self.selected_date, self.range_end) {
+            (CalendarSelectionMode::Range, Some(start), None) => {
+                if !self.range_contains_disabled_dates(start, date) {
+                    self.set_selected_range(start, date)
+                }
+            }
+            (CalendarSelectionMode::Multiple, ..) => self.toggle_date(date),
+            _ => self.set_selected_date(Some(date)),
+        }
+    }
+
+    /// Returns the earliest date available
+    pub fn min_date(&self) -> Option<NaiveDate> {
+        self.min_date
+    }
+
+    /// Sets the earliest date available
+    pub fn set_min_date(&mut self, min_date: Option<NaiveDate>) {
+        self.min_date = min_date;
+    }
+
+    /// Returns the latest date available
+    pub fn max_date(&self) -> Option<NaiveDate> {
+        self.max_date
+    }
+
+    /// Sets the latest date available
+    pub fn set_max_date(&mut self, max_date: Option<NaiveDate>) {
+        self.max_date = max_date;
+    }
+
+    /// Returns the dates that can't be clicked
+    pub fn disabled_dates(&self) -> &BTreeSet<NaiveDate> {
+        &self.disabled_dates
+    }
+
+    /// Forbids clicking the specified date
+    pub fn disable_date(&mut self, date: NaiveDate) {
+        self.disabled_dates.insert(date);
+    }
+
+    /// Allows clicking the specified date, if it's within the date bounds
+    pub fn enable_date(&mut self, date: NaiveDate) {
+        self.disabled_dates.remove(&date);
+    }
+
+    /// Returns `true` if the date is beyond the date bounds or is disabled
+    pub fn is_date_disabled(&self, date: NaiveDate) -> bool {
+        self.min_date.is_some_and(|min_date| date < min_date)
+            || self.max_date.is_some_and(|max_date| date > max_date)
+            || self.disabled_dates.contains(&date)
+    }
+
+    /// Returns `true` if at least one date between the `start` and the `end`
+    /// (inclusive) is disabled, see [`Calendar::is_date_disabled`]
+    pub fn range_contains_disabled_dates(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        range_dates(start, end).any(|date| self.is_date_disabled(date))
+    }
+
+    /// Returns `true` if the clicked day can be selected: it isn't disabled and
+    /// it doesn't complete the range containing the disabled days
+    pub(crate) fn can_select_day(
+        &self,
+        date: NaiveDate,
+        parameters: &CalendarSchemaParameters,
+    ) -> bool {
+        match (self.selection_mode, self.selected_date, self.range_end) {
+            (CalendarSelectionMode::Range, Some(start), None) => {
+                !range_dates(start, date).any(|date| self.is_day_disabled(date, parameters))
+            }
+            _ => !self.is_day_disabled(date, parameters),
+        }
+    }
+
+    /// Returns `true` if the day can't be clicked, i.e. the date is disabled
+    /// either by the widget itself or by the `is_day_disabled` schema parameter
+    pub(crate) fn is_day_disabled(
+        &self,
+        date: NaiveDate,
+        parameters: &CalendarSchemaParameters,
+    ) -> bool {
+        self.is_date_disabled(date)
+            || parameters.is_day_disabled.is_some_and(|is_day_disabled| is_day_disabled(date))
+    }
+
+    /// Returns `true` if at least one day of the month is within the date
+    /// bounds
+    pub fn is_month_within_bounds(&self, year: u32, month: u32) -> bool {
+        if !is_year_supported(year) {
+            return false;
+        }
+
+        let first_day = NaiveDate::from_ymd_opt(year as i32, month, 1).unwrap();
+        let last_day =
+            NaiveDate::from_ymd_opt(year as i32, month, days_in_month(year, month)).unwrap();
+
+        self.min_date.map_or(true, |min_date| last_day >= min_date)
+            && self.max_date.map_or(true, |max_date| first_day <= max_date)
+    }
+
+    /// Returns `true` if at least one day of the year is within the date bounds
+    pub fn is_year_within_bounds(&self, year: u32) -> bool {
+        is_year_supported(year)
+            && self.min_date.map_or(true, |min_date| year as i32 >= min_date.year())
+            && self.max_date.map_or(true, |max_date| year as i32 <= max_date.year())
+    }
+
+    /// Points the [`Calendar`] to the specified month if it's within the date
+    /// bounds
+    fn set_ym_within_bounds(&mut self, (year, month): (u32, u32)) {
+        if self.is_month_within_bounds(year, month) {
+            self.year = year;
+            self.month = month;
+        }
+    }
+
+    /// Points the [`Calendar`] to the nearest month within the date bounds
+    fn clamp_ym_to_bounds(&mut self) {
+        let mut ym = (self.year, self.month);
+        if let Some(min_date) = self.min_date {
+            ym = ym.max((min_date.year() as u32, min_date.month()));
+        }
+        if let Some(max_date) = self.max_date {
+            ym = ym.min((max_date.year() as u32, max_date.month()));
+        }
+        (self.year, self.month) = ym;
     }
 
     /// Points the [`Calendar`] to the current month and the current year
@@ -49,52 +400,129 @@ impl Calendar {
     }
 
     /// Points the [`Calendar`] to the previous year
+    ///
+    /// Does nothing if the month is beyond the date bounds
     pub fn set_previous_year(&mut self) {
-        self.year -= 1;
+        if let Some(year) = self.year.checked_sub(1) {
+            self.set_ym_within_bounds((year, self.month));
+        }
     }
 
     /// Points the [`Calendar`] to the next year
+    ///
+    /// Does nothing if the month is beyond the date bounds
     pub fn set_next_year(&mut self) {
-        self.year += 1;
+        if let Some(year) = self.year.checked_add(1) {
+            self.set_ym_within_bounds((year, self.month));
+        }
     }
 
     /// Points the [`Calendar`] to the previous month
+    ///
+    /// Does nothing if the month is beyond the date bounds
     pub fn set_previous_month(&mut self) {
-        if self.month == 1 {
-            self.month = 12;
-            self.year -= 1;
-        } else {
-            self.month -= 1;
+        if let Some(ym) = previous_month(self.year, self.month) {
+            self.set_ym_within_bounds(ym);
         }
     }
 
     /// Points the [`Calendar`] to the next month
+    ///
+    /// Does nothing if the month is beyond the date bounds
     pub fn set_next_month(&mut self) {
-        if self.month == 12 {
-            self.month = 1;
-            self.year += 1;
-        } else {
-            self.month += 1;
+        if let Some(ym) = next_month(self.year, self.month) {
+            self.set_ym_within_bounds(ym);
+        }
+    }
+
+    /// Points the [`Calendar`] to the clicked month of the selected year and
+    /// switches to the [`CalendarView::Days`]
+    ///
+    /// Does nothing if the month is beyond the date bounds
+    pub fn select_month(&mut self, month: u32) {
+        if (1..=12).contains(&month) && self.is_month_within_bounds(self.year, month) {
+            self.month = month;
+            self.view = CalendarView::Days;
+        }
+    }
+
+    /// Points the [`Calendar`] to the clicked year and switches to the
+    /// [`CalendarView::Months`]
+    ///
+    /// Does nothing if the year is beyond the date bounds. The month is moved
+    /// within the date bounds if it's needed
+    pub fn select_year(&mut self, year: u32) {
+        if self.is_year_within_bounds(year) {
+            self.year = year;
+            self.clamp_ym_to_bounds();
+            self.view = CalendarView::Months;
+        }
+    }
+
+    /// Returns the first year of the page displayed in the
+    /// [`CalendarView::Years`]
+    pub fn years_page_start(&self) -> u32 {
+        self.year - self.year % YEARS_PAGE_SIZE
+    }
+
+    /// Returns `true` if the year is displayed in the [`CalendarView::Years`]
+    fn is_year_displayed(&self, year: u32) -> bool {
+        let page_start = self.years_page_start();
+        self.view == CalendarView::Years
+            && (page_start..page_start + YEARS_PAGE_SIZE).contains(&year)
+    }
+
+    /// Returns `true` if at least one year of the page starting with the
+    /// `page_start` is within the date bounds
+    fn is_years_page_within_bounds(&self, page_start: u32) -> bool {
+        (page_start..page_start + YEARS_PAGE_SIZE).any(|year| self.is_year_within_bounds(year))
+    }
+
+    /// Points the [`Calendar`] to the previous page of years
+    ///
+    /// Does nothing if the page is beyond the date bounds
+    pub fn set_previous_years_page(&mut self) {
+        let previous_page_start = self.years_page_start().checked_sub(YEARS_PAGE_SIZE);
+        if previous_page_start
+            .is_some_and(|page_start| self.is_years_page_within_bounds(page_start))
+        {
+            self.year -= YEARS_PAGE_SIZE;
+        }
+    }
+
+    /// Points the [`Calendar`] to the next page of years
+    ///
+    /// Does nothing if the page is beyond the date bounds
+    pub fn set_next_years_page(&mut self) {
+        if self.is_years_page_within_bounds(self.years_page_start() + YEARS_PAGE_SIZE) {
+            self.year += YEARS_PAGE_SIZE;
+        }
+    }
+
+    fn apply(&mut self, action: CalendarAction) {
+        match action {
+            CalendarAction::PreviousYear => self.set_previous_year(),
+            CalendarAction::NextYear => self.set_next_year(),
+            CalendarAction::PreviousMonth => self.set_previous_month(),
+            CalendarAction::NextMonth => self.set_next_month(),
+            CalendarAction::DaysView => self.set_view(CalendarView::Days),
+            CalendarAction::MonthsView => self.set_view(CalendarView::Months),
+            CalendarAction::YearsView => self.set_view(CalendarView::Years),
+            CalendarAction::PreviousYearsPage => self.set_previous_years_page(),
+            CalendarAction::NextYearsPage => self.set_next_years_page(),
+            CalendarAction::SelectMonth(month) => self.select_month(month),
+            // The forged years are ignored, as well as the years of the stale keyboards
+            CalendarAction::SelectYear(year) => {
+                if self.is_year_displayed(year) {
+                    self.select_year(year)
+                }
+            }
         }
     }
 
     /// Returns the number of days in the selected month
     pub fn days_in_selected_month(&self) -> u32 {
-        let (year, month) = (self.year, self.month);
-        NaiveDate::from_ymd_opt(
-            match month {
-                12 => year + 1,
-                _ => year,
-            } as i32,
-            match month {
-                12 => 1,
-                _ => month + 1,
-            },
-            1,
-        )
-        .unwrap()
-        .signed_duration_since(NaiveDate::from_ymd_opt(year as i32, month, 1).unwrap())
-        .num_days() as u32
+        days_in_month(self.year, self.month)
     }
 
     /// Returns the first day in the selected month
@@ -108,46 +536,172 @@ impl Calendar {
             .unwrap()
     }
 
+    /// [`dptree`]-schema for the [`Calendar`] widget
+    ///
+    /// The clicked day is selected according to the [`CalendarSelectionMode`]
+    /// and the widget is redrawn, then:
+    /// - the `range_selected` handler is invoked if the range is completed, it
+    ///   receives `(NaiveDate, NaiveDate)`;
+    /// - otherwise the `day_click` handler is invoked, it receives the clicked
+    ///   [`NaiveDate`] and is supposed to answer the callback query.
+    ///
+    /// The `weekday_click` handler receives the clicked [`Weekday`] and is
+    /// supposed to answer the callback query
+    pub fn schema<W>(
+        parameters: &'static CalendarSchemaParameters,
+        day_click: Option<UpdateHandler<W::Err>>,
+        range_selected: Option<UpdateHandler<W::Err>>,
+        weekday_click: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let mut day_branch = dptree::filter_map(move |cq: CallbackQuery| {
+            NaiveDate::parse_from_str(cq.data?.strip_prefix(parameters.day_prefix)?, "%Y/%m/%d")
+                .ok()
+        })
+        .branch(
+            dptree::filter(move |mut widget: W, date: NaiveDate| {
+                !widget.get_widget().can_select_day(date, parameters)
+            })
+            .endpoint(
+                |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                    bot.answer_callback_query(cq_id).await?;
+                    log::warn!("User clicked on the disabled day or the range with disabled days");
+
+                    Ok(())
+                },
+            ),
+        )
+        .map(|mut widget: W, date: NaiveDate| {
+            widget.get_widget().select_date(date);
+            widget
+        });
+        if let Some(range_selected) = range_selected {
+            day_branch = day_branch.branch(
+                dptree::filter_map(|mut widget: W| widget.get_widget().selected_range())
+                    .chain(update_widget::<W>())
+                    .chain(range_selected),
+            );
+        }
+        let day_branch = match day_click {
+            Some(day_click) => day_branch.chain(redraw_widget::<W>()).chain(day_click),
+            None => day_branch.chain(update_widget::<W>()).endpoint(|| async { Ok(()) }),
+        };
+
+        let mut schema = dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .branch(day_branch);
+        if let Some(weekday_click) = weekday_click {
+            schema = schema.branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    Weekday::try_from(
+                        cq.data?.strip_prefix(parameters.weekday_prefix)?.parse::<u8>().ok()?,
+                    )
+                    .ok()
+                })
+                .chain(weekday_click),
+            );
+        }
+
+        schema
+            .branch(
+                dptree::filter(move |cq: CallbackQuery| {
+                    cq.data.as_deref() == Some(parameters.noop_data)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    CalendarAction::from_data(&cq.data?, parameters)
+                })
+                .map(|mut widget: W, action: CalendarAction| {
+                    widget.get_widget().apply(action);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
+            )
+    }
+
     pub fn inline_keyboard_markup(
         &self,
         parameters: &CalendarSchemaParameters,
         styles: &WidgetStyles,
     ) -> InlineKeyboardMarkup {
+        InlineKeyboardMarkup::new(match self.view {
+            CalendarView::Days => self.days_keyboard(parameters, styles),
+            CalendarView::Months => self.months_keyboard(parameters, styles),
+            CalendarView::Years => self.years_keyboard(parameters, styles),
+        })
+    }
+
+    fn days_keyboard(
+        &self,
+        parameters: &CalendarSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> Vec<Vec<InlineKeyboardButton>> {
         let mut keyboard: Vec<Vec<InlineKeyboardButton>> = Vec::with_capacity(8);
 
         let calendar_style = &styles.calendar_style;
+        let common_style = &styles.common_style;
+        // Navigation button which is hidden if the month it points to is beyond the
+        // date bounds
+        let navigation_button = |ym: Option<(u32, u32)>, icon: &Cow<'static, str>, data| {
+            if ym.is_some_and(|(year, month)| self.is_month_within_bounds(year, month)) {
+                InlineKeyboardButton::callback(icon.clone(), data)
+            } else {
+                InlineKeyboardButton::callback(
+                    common_style.empty_cell_icon.clone(),
+                    parameters.noop_data,
+                )
+            }
+        };
         // Calendar header
         keyboard.extend([
             vec![
-                InlineKeyboardButton::callback(
-                    calendar_style.previous_month_icon.clone(),
+                navigation_button(
+                    previous_month(self.year, self.month),
+                    &calendar_style.previous_month_icon,
                     parameters.previous_month_data,
                 ),
                 InlineKeyboardButton::callback(
                     calendar_style.months[(self.month - 1) as usize].clone(),
-                    parameters.noop_data,
+                    parameters.months_view_data,
                 ),
-                InlineKeyboardButton::callback(
-                    calendar_style.next_month_icon.clone(),
+                navigation_button(
+                    next_month(self.year, self.month),
+                    &calendar_style.next_month_icon,
                     parameters.next_month_data,
                 ),
-                InlineKeyboardButton::callback(
-                    calendar_style.previous_year_icon.clone(),
+                navigation_button(
+                    self.year.checked_sub(1).map(|year| (year, self.month)),
+                    &calendar_style.previous_year_icon,
                     parameters.previous_year_data,
                 ),
-                InlineKeyboardButton::callback(self.year.to_string(), parameters.noop_data),
-                InlineKeyboardButton::callback(
-                    calendar_style.next_year_icon.clone(),
+                InlineKeyboardButton::callback(self.year.to_string(), parameters.years_view_data),
+                navigation_button(
+                    self.year.checked_add(1).map(|year| (year, self.month)),
+                    &calendar_style.next_year_icon,
                     parameters.next_year_data,
                 ),
             ],
-            calendar_style
-                .days_of_the_week
-                .iter()
-                .enumerate()
-                .map(|(i, weekday)| {
+            // The names of the days of the week start with Monday, so they are rotated
+            // according to the first day of the week
+            successors(Some(self.first_weekday), |weekday| Some(weekday.succ()))
+                .take(7)
+                .map(|weekday| {
+                    let i = weekday.num_days_from_monday();
                     InlineKeyboardButton::callback(
-                        weekday.clone(),
+                        calendar_style.days_of_the_week[i as usize].clone(),
                         format!("{}{}", parameters.weekday_prefix, i),
                     )
                 })
@@ -159,8 +713,7 @@ impl Calendar {
                 .unwrap();
 
         let mut day_buttons: Vec<InlineKeyboardButton> = Vec::with_capacity(31);
-        let common_style = &styles.common_style;
-        let top_empty_cells_quantity = month_first_day.weekday().num_days_from_monday();
+        let top_empty_cells_quantity = month_first_day.weekday().days_since(self.first_weekday);
         day_buttons.extend(
             repeat(InlineKeyboardButton::callback(
                 common_style.empty_cell_icon.clone(),
@@ -168,14 +721,47 @@ impl Calendar {
             ))
             .take(top_empty_cells_quantity as usize),
         );
+        let today = Local::now().date_naive();
         day_buttons.extend((1..=self.days_in_selected_month()).map(|day| {
-            InlineKeyboardButton::callback(
-                day.to_string(),
-                format!("{}{}/{}/{}", parameters.day_prefix, self.year, self.month, day),
-            )
+            let date = NaiveDate::from_ymd_opt(self.year as i32, self.month, day).unwrap();
+            if self.is_day_disabled(date, parameters) {
+                InlineKeyboardButton::callback(
+                    calendar_style.disabled_day_format.replace("{day}", &day.to_string()),
+                    parameters.noop_data,
+                )
+            } else if self.selection_mode == CalendarSelectionMode::Multiple {
+                // The days are displayed like the items of the `CheckboxList`
+                let icon = if self.selected_dates.contains(&date) {
+                    &styles.checkbox_list_style.active_icon
+                } else {
+                    &styles.checkbox_list_style.inactive_icon
+                };
+                InlineKeyboardButton::callback(
+                    format!("{icon} {day}"),
+                    format!("{}{}/{}/{}", parameters.day_prefix, self.year, self.month, day),
+                )
+            } else {
+                let day_format: &str =
+                    if self.selected_date == Some(date) || self.range_end == Some(date) {
+                        &calendar_style.selected_day_format
+                    } else if self
+                        .selected_range()
+                        .is_some_and(|(start, end)| start < date && date < end)
+                    {
+                        &calendar_style.range_day_format
+                    } else if date == today {
+                        &calendar_style.today_format
+                    } else {
+                        "{day}"
+                    };
+                InlineKeyboardButton::callback(
+                    day_format.replace("{day}", &day.to_string()),
+                    format!("{}{}/{}/{}", parameters.day_prefix, self.year, self.month, day),
+                )
+            }
         }));
         let bottom_empty_cells_quantity: u32 =
-            7u32 - month_last_day.weekday().num_days_from_monday() - 1;
+            7u32 - month_last_day.weekday().days_since(self.first_weekday) - 1;
         day_buttons.extend(
             repeat(InlineKeyboardButton::callback(
                 common_style.empty_cell_icon.clone(),
@@ -188,13 +774,157 @@ impl Calendar {
             keyboard.push(row.to_vec())
         }
 
-        InlineKeyboardMarkup::new(keyboard)
+        keyboard
+    }
+
+    fn months_keyboard(
+        &self,
+        parameters: &CalendarSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> Vec<Vec<InlineKeyboardButton>> {
+        let calendar_style = &styles.calendar_style;
+
+        let mut keyboard = vec![vec![
+            InlineKeyboardButton::callback(
+                calendar_style.days_view_icon.clone(),
+                parameters.days_view_data,
+            ),
+            InlineKeyboardButton::callback(self.year.to_string(), parameters.years_view_data),
+        ]];
+        let month_buttons = calendar_style
+            .months
+            .iter()
+            .zip(1..)
+            .map(|(month_name, month)| {
+                if self.is_month_within_bounds(self.year, month) {
+                    InlineKeyboardButton::callback(
+                        month_name.clone(),
+                        format!("{}{month}", parameters.month_prefix),
+                    )
+                } else {
+                    InlineKeyboardButton::callback(
+                        calendar_style.disabled_cell_format.replace("{cell}", month_name),
+                        parameters.noop_data,
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        keyboard.extend(month_buttons.chunks(GRID_COLUMNS).map(<[_]>::to_vec));
+
+        keyboard
+    }
+
+    fn years_keyboard(
+        &self,
+        parameters: &CalendarSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> Vec<Vec<InlineKeyboardButton>> {
+        let calendar_style = &styles.calendar_style;
+        let common_style = &styles.common_style;
+
+        let page_start = self.years_page_start();
+        let page_end = page_start + YEARS_PAGE_SIZE - 1;
+        // Navigation button which is hidden if the page it points to is beyond the
+        // date bounds
+        let navigation_button = |page_start: Option<u32>, icon: &Cow<'static, str>, data| {
+            if page_start.is_some_and(|page_start| self.is_years_page_within_bounds(page_start)) {
+                InlineKeyboardButton::callback(icon.clone(), data)
+            } else {
+                InlineKeyboardButton::callback(
+                    common_style.empty_cell_icon.clone(),
+                    parameters.noop_data,
+                )
+            }
+        };
+
+        let mut keyboard = vec![vec![
+            InlineKeyboardButton::callback(
+                calendar_style.days_view_icon.clone(),
+                parameters.days_view_data,
+            ),
+            navigation_button(
+                page_start.checked_sub(YEARS_PAGE_SIZE),
+                &calendar_style.previous_year_icon,
+                parameters.previous_years_page_data,
+            ),
+            InlineKeyboardButton::callback(
+                format!("{page_start} - {page_end}"),
+                parameters.noop_data,
+            ),
+            navigation_button(
+                Some(page_start + YEARS_PAGE_SIZE),
+                &calendar_style.next_year_icon,
+                parameters.next_years_page_data,
+            ),
+        ]];
+        let year_buttons = (page_start..=page_end)
+            .map(|year| {
+                if self.is_year_within_bounds(year) {
+                    InlineKeyboardButton::callback(
+                        year.to_string(),
+                        format!("{}{year}", parameters.year_prefix),
+                    )
+                } else {
+                    InlineKeyboardButton::callback(
+                        calendar_style.disabled_cell_format.replace("{cell}", &year.to_string()),
+                        parameters.noop_data,
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        keyboard.extend(year_buttons.chunks(GRID_COLUMNS).map(<[_]>::to_vec));
+
+        keyboard
     }
 }
 
 impl GetSize for Calendar {
     fn size(&self) -> Size {
-        Size { rows: 8, columns: 7 }
+        match self.view {
+            CalendarView::Days => Size { rows: 8, columns: 7 },
+            CalendarView::Months | CalendarView::Years => Size { rows: 4, columns: 4 },
+        }
+    }
+}
+
+/// Returns the number of days in the month
+fn days_in_month(year: u32, month: u32) -> u32 {
+    (28..=31).rev().find(|&day| NaiveDate::from_ymd_opt(year as i32, month, day).is_some()).unwrap()
+}
+
+/// Returns `true` if the year is within the [`NaiveDate`] range
+fn is_year_supported(year: u32) -> bool {
+    i32::try_from(year)
+        .is_ok_and(|year| (NaiveDate::MIN.year()..=NaiveDate::MAX.year()).contains(&year))
+}
+
+/// Returns the day of the week displayed in the first column by default
+fn default_first_weekday() -> Weekday {
+    Weekday::Mon
+}
+
+/// Returns the dates between the `start` and the `end` (inclusive) in the
+/// ascending order
+fn range_dates(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    let (start, end) = (start.min(end), start.max(end));
+    start.iter_days().take_while(move |date| *date <= end)
+}
+
+/// Returns the year and the month preceding the specified ones, `None` is
+/// returned if the year overflows
+fn previous_month(year: u32, month: u32) -> Option<(u32, u32)> {
+    match month {
+        1 => Some((year.checked_sub(1)?, 12)),
+        _ => Some((year, month - 1)),
+    }
+}
+
+/// Returns the year and the month following the specified ones, `None` is
+/// returned if the year overflows
+fn next_month(year: u32, month: u32) -> Option<(u32, u32)> {
+    match month {
+        12 => Some((year.checked_add(1)?, 1)),
+        _ => Some((year, month + 1)),
     }
 }
 
@@ -206,4 +936,332 @@ pub struct CalendarSchemaParameters {
     pub previous_month_data: &'static str,
     pub next_month_data: &'static str,
     pub noop_data: &'static str,
+    pub month_prefix: &'static str,
+    pub year_prefix: &'static str,
+    /// CallbackQuery data of the button returning from the months and the
+    /// years views to the [`CalendarView::Days`]
+    pub days_view_data: &'static str,
+    pub months_view_data: &'static str,
+    pub years_view_data: &'static str,
+    pub previous_years_page_data: &'static str,
+    pub next_years_page_data: &'static str,
+    /// Predicate for the days that can't be clicked
+    pub is_day_disabled: Option<fn(NaiveDate) -> bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::{types::InlineKeyboardButtonKind, Bot};
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<CalendarWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct CalendarWidget {
+        #[calendar]
+        calendar: Calendar,
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn parameters() -> CalendarSchemaParameters {
+        CalendarSchemaParameters {
+            day_prefix: "d_",
+            weekday_prefix: "w_",
+            previous_year_data: "py",
+            next_year_data: "ny",
+            previous_month_data: "pm",
+            next_month_data: "nm",
+            noop_data: "noop",
+            month_prefix: "m_",
+            year_prefix: "y_",
+            days_view_data: "dv",
+            months_view_data: "mv",
+            years_view_data: "yv",
+            previous_years_page_data: "pyp",
+            next_years_page_data: "nyp",
+            is_day_disabled: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn forged_years() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_view(CalendarView::Years);
+        let widget = CalendarWidget { calendar };
+
+        // The years beyond the `NaiveDate` range, as well as the years of the other
+        // pages, aren't displayed
+        for year in ["4000000000", "262143", "0", "2036"] {
+            let calendar =
+                dispatch(widget.clone(), &format!("y_{year}")).await.widget.unwrap().calendar;
+            assert_eq!((calendar.year, calendar.view), (2024, CalendarView::Years));
+        }
+
+        let calendar = dispatch(widget, "y_2020").await.widget.unwrap().calendar;
+        assert_eq!((calendar.year, calendar.view), (2020, CalendarView::Months));
+    }
+
+    #[test]
+    fn supported_years() {
+        let styles = WidgetStyles::default();
+
+        let mut calendar = Calendar::with_ym(0, 1);
+        calendar.set_previous_year();
+        calendar.set_previous_month();
+        assert_eq!((calendar.year, calendar.month), (0, 1));
+        let keyboard = calendar.inline_keyboard_markup(&parameters(), &styles).inline_keyboard;
+        // Previous month and previous year buttons are hidden
+        assert_eq!(keyboard[0][0].text, "✖️");
+        assert_eq!(keyboard[0][3].text, "✖️");
+        calendar.set_view(CalendarView::Years);
+        calendar.set_previous_years_page();
+        assert_eq!(calendar.years_page_start(), 0);
+
+        let max_year = NaiveDate::MAX.year() as u32;
+        let mut calendar = Calendar::with_ym(max_year, 12);
+        calendar.set_next_year();
+        calendar.set_next_month();
+        assert_eq!((calendar.year, calendar.month), (max_year, 12));
+        let keyboard = calendar.inline_keyboard_markup(&parameters(), &styles).inline_keyboard;
+        // Next month and next year buttons are hidden
+        assert_eq!(keyboard[0][2].text, "✖️");
+        assert_eq!(keyboard[0][5].text, "✖️");
+        calendar.set_view(CalendarView::Years);
+        calendar.set_next_years_page();
+        assert_eq!(calendar.year, max_year);
+        calendar.inline_keyboard_markup(&parameters(), &styles);
+    }
+
+    #[test]
+    fn days_in_month() {
+        assert_eq!(Calendar::with_ym(2024, 2).days_in_selected_month(), 29);
+        assert_eq!(Calendar::with_ym(2023, 12).days_in_selected_month(), 31);
+    }
+
+    #[test]
+    fn bounds() {
+        let mut calendar = Calendar::with_ym(2024, 3);
+        calendar.set_min_date(Some(date(2024, 2, 15)));
+        calendar.set_max_date(Some(date(2024, 4, 1)));
+
+        calendar.set_previous_year();
+        calendar.set_next_year();
+        assert_eq!((calendar.year, calendar.month), (2024, 3));
+
+        calendar.set_previous_month();
+        calendar.set_previous_month();
+        assert_eq!((calendar.year, calendar.month), (2024, 2));
+
+        calendar.set_next_month();
+        calendar.set_next_month();
+        calendar.set_next_month();
+        assert_eq!((calendar.year, calendar.month), (2024, 4));
+
+        assert!(calendar.is_date_disabled(date(2024, 4, 2)));
+        assert!(!calendar.is_date_disabled(date(2024, 4, 1)));
+    }
+
+    #[test]
+    fn disabled_days() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_min_date(Some(date(2024, 7, 2)));
+        calendar.disable_date(date(2024, 7, 3));
+
+        let parameters = CalendarSchemaParameters {
+            is_day_disabled: Some(|date| date.day() == 4),
+            ..parameters()
+        };
+        let keyboard =
+            calendar.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+
+        // July 2024 starts on Monday
+        let days = keyboard[2].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(days[..5], ["(1)", "2", "(3)", "(4)", "5"]);
+        // Previous month and previous year buttons are hidden
+        assert_eq!(keyboard[0][0].text, "✖️");
+        assert_eq!(keyboard[0][3].text, "✖️");
+        assert_eq!(keyboard[0][2].text, "▶️");
+
+        // Clicks on the days disabled by either the widget or the schema parameters are
+        // rejected by the schema
+        assert!(calendar.is_day_disabled(date(2024, 7, 1), &parameters));
+        assert!(calendar.is_day_disabled(date(2024, 7, 3), &parameters));
+        assert!(calendar.is_day_disabled(date(2024, 7, 4), &parameters));
+        assert!(!calendar.is_day_disabled(date(2024, 7, 5), &parameters));
+    }
+
+    #[test]
+    fn selected_day() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_selected_date(Some(date(2024, 7, 2)));
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 2)));
+
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard[2][1].text, "[2]");
+        assert_eq!(keyboard[2][2].text, "3");
+
+        // The selected day stays the same when the month is changed
+        calendar.set_next_month();
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 2)));
+
+        // The disabled dates can't be selected
+        calendar.disable_date(date(2024, 7, 5));
+        calendar.select_date(date(2024, 7, 5));
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 2)));
+        calendar.select_date(date(2024, 7, 6));
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 6)));
+    }
+
+    #[test]
+    fn selected_range() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_selection_mode(CalendarSelectionMode::Range);
+
+        calendar.select_date(date(2024, 7, 4));
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 4)));
+        assert_eq!(calendar.selected_range(), None);
+
+        // The dates are swapped if the end precedes the start
+        calendar.select_date(date(2024, 7, 2));
+        assert_eq!(calendar.selected_range(), Some((date(2024, 7, 2), date(2024, 7, 4))));
+
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        let days = keyboard[2].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(days[..5], ["1", "[2]", "~3~", "[4]", "5"]);
+
+        // The third click starts the new range
+        calendar.select_date(date(2024, 7, 10));
+        assert_eq!(calendar.selected_date(), Some(date(2024, 7, 10)));
+        assert_eq!(calendar.selected_range(), None);
+
+        // The range can't contain the disabled dates
+        calendar.disable_date(date(2024, 7, 12));
+        assert!(calendar.range_contains_disabled_dates(date(2024, 7, 13), date(2024, 7, 10)));
+        assert!(!calendar.can_select_day(date(2024, 7, 13), &parameters()));
+        calendar.select_date(date(2024, 7, 13));
+        assert_eq!(calendar.selected_range(), None);
+
+        let parameters = CalendarSchemaParameters {
+            is_day_disabled: Some(|date| date.day() == 11),
+            ..parameters()
+        };
+        assert!(!calendar.range_contains_disabled_dates(date(2024, 7, 10), date(2024, 7, 11)));
+        assert!(!calendar.can_select_day(date(2024, 7, 11), &parameters));
+        assert!(calendar.can_select_day(date(2024, 7, 9), &parameters));
+        calendar.select_date(date(2024, 7, 9));
+        assert_eq!(calendar.selected_range(), Some((date(2024, 7, 9), date(2024, 7, 10))));
+    }
+
+    #[test]
+    fn selected_dates() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_selection_mode(CalendarSelectionMode::Multiple);
+
+        calendar.select_date(date(2024, 7, 2));
+        calendar.select_date(date(2024, 7, 4));
+        calendar.set_next_month();
+        calendar.select_date(date(2024, 8, 1));
+        calendar.select_date(date(2024, 7, 4));
+        assert_eq!(
+            calendar.selected_dates().iter().copied().collect::<Vec<_>>(),
+            [date(2024, 7, 2), date(2024, 8, 1)]
+        );
+
+        calendar.set_previous_month();
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        let days = keyboard[2].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(days[..4], ["☐ 1", "☑ 2", "☐ 3", "☐ 4"]);
+    }
+
+    #[test]
+    fn first_weekday() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_first_weekday(Weekday::Sun);
+
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        let weekdays = keyboard[1].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(weekdays, ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]);
+        // The index of the day of the week doesn't depend on the column
+        assert_eq!(keyboard[1][0].kind, InlineKeyboardButtonKind::CallbackData("w_6".to_owned()));
+        // July 2024 starts on Monday and ends on Wednesday
+        assert_eq!(keyboard[2][0].text, "✖️");
+        assert_eq!(keyboard[2][1].text, "1");
+        assert_eq!(keyboard[6][3].text, "31");
+        assert_eq!(keyboard[6].len(), 7);
+    }
+
+    #[test]
+    fn drill_down() {
+        let mut calendar = Calendar::with_ym(2024, 7);
+        calendar.set_min_date(Some(date(2023, 3, 10)));
+        calendar.set_view(CalendarView::Years);
+
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "↩️");
+        assert_eq!(keyboard[0][2].text, "2016 - 2027");
+        // The previous page is beyond the date bounds
+        assert_eq!(keyboard[0][1].text, "✖️");
+        assert_eq!(keyboard[2][2].text, "(2022)");
+        assert_eq!(keyboard[2][3].text, "2023");
+        assert_eq!(keyboard.len() as u8, calendar.size().rows);
+
+        calendar.set_previous_years_page();
+        assert_eq!(calendar.years_page_start(), 2016);
+        calendar.set_next_years_page();
+        assert_eq!(calendar.years_page_start(), 2028);
+
+        calendar.select_year(2022);
+        assert_eq!(calendar.view(), CalendarView::Years);
+        calendar.select_year(2023);
+        assert_eq!(calendar.view(), CalendarView::Months);
+
+        let keyboard = calendar
+            .inline_keyboard_markup(&parameters(), &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "↩️");
+        assert_eq!(keyboard[0][1].text, "2023");
+        assert_eq!(keyboard[1][1].text, "(February)");
+        assert_eq!(keyboard[1][2].text, "March");
+
+        calendar.select_month(2);
+        assert_eq!(calendar.view(), CalendarView::Months);
+        calendar.select_month(3);
+        assert_eq!(calendar.view(), CalendarView::Days);
+        assert_eq!(calendar.first_day_in_month(), date(2023, 3, 1));
+    }
+
+    #[test]
+    fn drill_down_bounds() {
+        let mut calendar = Calendar::with_ym(2024, 1);
+        calendar.set_min_date(Some(date(2023, 3, 10)));
+        calendar.set_max_date(Some(date(2030, 5, 1)));
+
+        // The month is moved within the date bounds of the selected year
+        calendar.select_year(2023);
+        assert_eq!(calendar.first_day_in_month(), date(2023, 3, 1));
+
+        // The page of years may point beyond the date bounds, the days view doesn't
+        calendar.set_view(CalendarView::Years);
+        calendar.set_next_years_page();
+        calendar.set_next_years_page();
+        assert_eq!(calendar.years_page_start(), 2028);
+        calendar.set_view(CalendarView::Days);
+        assert_eq!(calendar.first_day_in_month(), date(2030, 5, 1));
+    }
 }
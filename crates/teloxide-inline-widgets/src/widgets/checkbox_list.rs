@@ -4,26 +4,69 @@ use serde::{Deserialize, Serialize};
 use teloxide::{
     dispatching::UpdateHandler,
     dptree,
+    payloads::AnswerCallbackQuerySetters,
     prelude::Requester,
     types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
 };
 
 use crate::{
+    handlers::update_widget,
     traits::{GetSize, InlineWidget, WidgetContainer},
-    types::{Size, WidgetStyles},
+    types::{CheckboxListStyle, Size, WidgetStyles},
+    widgets::pagination::{default_size, pages_count, paginated_size, pagination_row},
 };
 
 /// Checkbox list widget
+///
+/// If the items don't fit within the `size`, the [`CheckboxList`] is split
+/// into pages with the navigation row beneath the items
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CheckboxList<T> {
-    /// Size of the [`CheckboxList`] widget
+    /// Size of the items area of the [`CheckboxList`] widget (excluding the
+    /// pagination row)
     pub size: Size,
     items: Vec<(bool, T)>,
+    /// Currently displayed page, 0-based
+    #[serde(default)]
+    page: usize,
+    /// Minimal number of selected items
+    min_selected: Option<usize>,
+    /// Maximal number of selected items
+    max_selected: Option<usize>,
 }
 
+/// Index of a [`CheckboxList`] item. Used as a unique type in the
+/// [`dptree`]-handler schema
 #[derive(Debug, Clone)]
 pub struct CheckboxListItemIndex(pub usize);
 
+/// Index of a [`CheckboxList`] page. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone)]
+pub struct CheckboxListPage(pub usize);
+
+/// Selection limit of a [`CheckboxList`] that forbids toggling an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxListLimit {
+    /// At least the specified number of items must be selected
+    Min(usize),
+    /// At most the specified number of items can be selected
+    Max(usize),
+}
+
+impl CheckboxListLimit {
+    /// Returns the explanatory text for the user, the `{limit}` placeholder of
+    /// the style text is replaced with the limit value
+    pub fn text(&self, style: &CheckboxListStyle) -> String {
+        let (text, limit) = match self {
+            Self::Min(limit) => (&style.min_selected_text, limit),
+            Self::Max(limit) => (&style.max_selected_text, limit),
+        };
+
+        text.replace("{limit}", &limit.to_string())
+    }
+}
+
 impl<T> CheckboxList<T> {
     /// Creates new [`CheckboxList`] instance from a collection of (`bool`, `T`)
     /// items.
@@ -31,7 +74,7 @@ impl<T> CheckboxList<T> {
     /// If you want to create an instance with selected values, pass _true_ with
     /// these values.
     pub fn new(items: impl IntoIterator<Item = (bool, T)>, size: Size) -> Self {
-        Self { items: Vec::from_iter(items), size }
+        Self { items: Vec::from_iter(items), size, page: 0, min_selected: None, max_selected: None }
     }
 
     /// Toggles the selection of the item specified by the index
@@ -43,47 +86,210 @@ impl<T> CheckboxList<T> {
         self.items[i].0 = !self.items[i].0;
     }
 
+    /// Toggles the selection of the item specified by the index if the
+    /// selection limits of the [`CheckboxList`] allow it
+    ///
+    /// Panics if the index is out of bounds
+    pub fn try_toggle(&mut self, i: usize) -> Result<(), CheckboxListLimit> {
+        if let Some(limit) = self.violated_limit(i, None, None) {
+            return Err(limit);
+        }
+
+        self.toggle(i);
+        Ok(())
+    }
+
+    /// Returns the minimal number of selected items
+    pub fn min_selected(&self) -> Option<usize> {
+        self.min_selected
+    }
+
+    /// Sets the minimal number of selected items. Doesn't change the current
+    /// selection
+    pub fn set_min_selected(&mut self, min_selected: Option<usize>) {
+        self.min_selected = min_selected;
+    }
+
+    /// Returns the maximal number of selected items
+    pub fn max_selected(&self) -> Option<usize> {
+        self.max_selected
+    }
+
+    /// Sets the maximal number of selected items. Doesn't change the current
+    /// selection
+    pub fn set_max_selected(&mut self, max_selected: Option<usize>) {
+        self.max_selected = max_selected;
+    }
+
+    /// Returns `true` if the number of selected items satisfies the selection
+    /// limits of the [`CheckboxList`]
+    pub fn is_selection_valid(&self) -> bool {
+        let selected_count = self.selected_items().count();
+
+        self.min_selected.map_or(true, |min| selected_count >= min)
+            && self.max_selected.map_or(true, |max| selected_count <= max)
+    }
+
+    /// Returns the limit that forbids toggling the item specified by the
+    /// index.
+    ///
+    /// The limits of the [`CheckboxList`] take precedence over the specified
+    /// `default` ones
+    fn violated_limit(
+        &self,
+        i: usize,
+        default_min: Option<usize>,
+        default_max: Option<usize>,
+    ) -> Option<CheckboxListLimit> {
+        assert!(i < self.items.len());
+
+        let selected_count = self.selected_items().count();
+        if self.items[i].0 {
+            let min = self.min_selected.or(default_min)?;
+            (selected_count <= min).then_some(CheckboxListLimit::Min(min))
+        } else {
+            let max = self.max_selected.or(default_max)?;
+            (selected_count >= max).then_some(CheckboxListLimit::Max(max))
+        }
+    }
+
+    /// Returns `true` if the item specified by the index is selected
+    ///
+    /// Panics if the index is out of bounds
+    pub fn is_selected(&self, i: usize) -> bool {
+        self.items[i].0
+    }
+
     /// Returns the iterator over the selected items in the [`CheckboxList`]
     pub fn selected_items(&self) -> impl Iterator<Item = &T> {
         self.items.iter().filter_map(|(selected, item)| if *selected { Some(item) } else { None })
     }
 
+    /// Returns the index of the currently displayed page
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages required to display all the items
+    pub fn pages_count(&self) -> usize {
+        pages_count(self.items.len(), self.size)
+    }
+
+    /// Sets the currently displayed page by index
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_page(&mut self, page: usize) {
+        assert!(page < self.pages_count());
+
+        self.page = page;
+    }
+
     /// [`dptree`]-schema for the [`CheckboxList`] widget
-    pub fn schema<W>(parameters: &'static CheckboxListSchemaParameters) -> UpdateHandler<W::Err>
+    ///
+    /// The `on_toggle` handler is invoked after the item is toggled and the
+    /// widget is redrawn. It receives the updated `W` widget and the
+    /// [`CheckboxListItemIndex`] of the toggled item
+    ///
+    /// If toggling the item violates the selection limits, the callback query
+    /// is answered with the explanatory text and the widget isn't changed
+    pub fn schema<W>(
+        parameters: &'static CheckboxListSchemaParameters,
+        on_toggle: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
     where
         W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
         W::Bot: 'static + Clone + Send + Sync,
         W::Dialogue: 'static + Clone + Send + Sync,
     {
+        let on_toggle = on_toggle.unwrap_or_else(dptree::entry);
+
         dptree::entry()
-            .filter_map(move |cq: CallbackQuery| {
-                Some(CheckboxListItemIndex(cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?))
-            })
             .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
-            .endpoint(
-                |bot: W::Bot,
-                 dialogue: W::Dialogue,
-                 mut widget: W,
-                 (chat_id, message_id, cq_id): (ChatId, MessageId, String),
-                 widget_styles: WidgetStyles,
-                 CheckboxListItemIndex(i): CheckboxListItemIndex| async move {
-                    bot.answer_callback_query(cq_id).await?;
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    Some(CheckboxListItemIndex(
+                        cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?,
+                    ))
+                })
+                .branch(
+                    dptree::filter(
+                        |mut widget: W, CheckboxListItemIndex(i): CheckboxListItemIndex| {
+                            i >= widget.get_widget().items.len()
+                        },
+                    )
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the CheckboxList item that doesn't exist");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .branch(
+                    dptree::filter_map(
+                        move |mut widget: W, CheckboxListItemIndex(i): CheckboxListItemIndex| {
+                            widget.get_widget().violated_limit(
+                                i,
+                                parameters.min_selected,
+                                parameters.max_selected,
+                            )
+                        },
+                    )
+                    .endpoint(
+                        |bot: W::Bot,
+                         (_, _, cq_id): (ChatId, MessageId, String),
+                         widget_styles: WidgetStyles,
+                         limit: CheckboxListLimit| async move {
+                            bot.answer_callback_query(cq_id)
+                                .text(limit.text(&widget_styles.checkbox_list_style))
+                                .await?;
 
+                            Ok(())
+                        },
+                    ),
+                )
+                .map(|mut widget: W, CheckboxListItemIndex(i): CheckboxListItemIndex| {
                     widget.get_widget().toggle(i);
-                    // It's safe to update the view (keyboard) before the state if updates are
-                    // processed consistently in a single chat, so there is no
-                    // races
-                    widget.redraw(&bot, chat_id, message_id, &widget_styles).await?;
-                    widget.update_state(&dialogue).await?;
-
-                    Ok(())
-                },
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .branch(on_toggle)
+                .endpoint(|| async { Ok(()) }),
+            )
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    Some(CheckboxListPage(
+                        cq.data?.strip_prefix(parameters.page_prefix)?.parse().ok()?,
+                    ))
+                })
+                .branch(
+                    dptree::filter(|mut widget: W, CheckboxListPage(page): CheckboxListPage| {
+                        page >= widget.get_widget().pages_count()
+                    })
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the CheckboxList page that doesn't exist");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .map(|mut widget: W, CheckboxListPage(page): CheckboxListPage| {
+                    widget.get_widget().set_page(page);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
             )
     }
 
     /// Creates the [`InlineKeyboardMarkup`] for a [`CheckboxList`] widget with
     /// specified callback query `prefix` and size.
     ///
+    /// Only the items of the current page are displayed
+    ///
     /// It's not supposed to be used directly
     pub fn inline_keyboard_markup(
         &self,
@@ -107,9 +313,12 @@ impl<T> CheckboxList<T> {
         .take(rows as usize)
         .collect();
 
-        for (row_i, row_chunk) in self.items.chunks(columns as usize).enumerate() {
+        let page_size = rows as usize * columns as usize;
+        let page_offset = self.page * page_size;
+        let page_items = self.items.iter().skip(page_offset).take(page_size).collect::<Vec<_>>();
+        for (row_i, row_chunk) in page_items.chunks(columns.max(1) as usize).enumerate() {
             for (column_i, (active, item)) in row_chunk.iter().enumerate() {
-                let i = (row_i * columns as usize) + column_i;
+                let i = page_offset + (row_i * columns as usize) + column_i;
                 let icon = if *active {
                     &styles.checkbox_list_style.active_icon
                 } else {
@@ -123,6 +332,17 @@ impl<T> CheckboxList<T> {
             }
         }
 
+        let pages_count = self.pages_count();
+        if pages_count > 1 {
+            keyboard.push(pagination_row(
+                self.page,
+                pages_count,
+                parameters.page_prefix,
+                parameters.noop_data,
+                styles,
+            ));
+        }
+
         InlineKeyboardMarkup::new(keyboard)
     }
 }
@@ -141,27 +361,36 @@ impl<T> FromIterator<(bool, T)> for CheckboxList<T> {
 
 impl<T> From<Vec<T>> for CheckboxList<T> {
     fn from(value: Vec<T>) -> Self {
-        let size = Size::new(1, value.len() as u8);
+        let size = default_size(value.len());
         CheckboxList::new(value.into_iter().map(|item| (false, item)), size)
     }
 }
 
 impl<T> From<Vec<(bool, T)>> for CheckboxList<T> {
     fn from(value: Vec<(bool, T)>) -> Self {
-        let size = Size::new(1, value.len() as u8);
+        let size = default_size(value.len());
         CheckboxList::new(value, size)
     }
 }
 
 impl<T> GetSize for CheckboxList<T> {
     fn size(&self) -> Size {
-        self.size
+        paginated_size(self.size, self.pages_count())
     }
 }
 
 pub struct CheckboxListSchemaParameters {
     pub prefix: &'static str,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: &'static str,
     pub noop_data: &'static str,
+    /// Minimal number of selected items, is used if the [`CheckboxList`]
+    /// doesn't specify its own
+    pub min_selected: Option<usize>,
+    /// Maximal number of selected items, is used if the [`CheckboxList`]
+    /// doesn't specify its own
+    pub max_selected: Option<usize>,
 }
 
 #[cfg(test)]
@@ -175,6 +404,7 @@ mod tests {
 
         cl.toggle(2);
         assert_eq!(cl.selected_items().count(), 0);
+        assert!(!cl.is_selected(2));
 
         cl.toggle(0);
         cl.toggle(1);
@@ -189,4 +419,53 @@ mod tests {
 
         cl.toggle(1);
     }
+
+    #[test]
+    fn pagination() {
+        let mut cl = CheckboxList::new((1..=7).map(|i| (i == 7, i)), Size::new(1, 3));
+        assert_eq!(cl.pages_count(), 3);
+        assert_eq!(cl.size(), Size::new(2, 3));
+
+        cl.set_page(2);
+        let parameters = CheckboxListSchemaParameters {
+            prefix: "c_",
+            page_prefix: "c_p_",
+            noop_data: "noop",
+            min_selected: None,
+            max_selected: None,
+        };
+        let keyboard =
+            cl.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "☑ 7");
+        assert_eq!(keyboard[1][1].text, "3/3");
+    }
+
+    #[test]
+    fn limits() {
+        let mut cl = CheckboxList::new([(true, 1), (false, 2), (false, 3)], Size::new(1, 3));
+        cl.set_min_selected(Some(1));
+        cl.set_max_selected(Some(2));
+
+        assert_eq!(cl.try_toggle(0), Err(CheckboxListLimit::Min(1)));
+        assert_eq!(cl.try_toggle(1), Ok(()));
+        assert_eq!(cl.try_toggle(2), Err(CheckboxListLimit::Max(2)));
+        assert_eq!(cl.try_toggle(0), Ok(()));
+        assert!(cl.is_selection_valid());
+
+        cl.set_min_selected(Some(2));
+        assert!(!cl.is_selection_valid());
+    }
+
+    #[test]
+    fn default_limits() {
+        let mut cl = CheckboxList::new([(true, 1), (false, 2)], Size::new(1, 2));
+        assert_eq!(cl.violated_limit(1, None, Some(1)), Some(CheckboxListLimit::Max(1)));
+
+        cl.set_max_selected(Some(2));
+        assert_eq!(cl.violated_limit(1, None, Some(1)), None);
+        assert_eq!(
+            CheckboxListLimit::Max(1).text(&CheckboxListStyle::default()),
+            "You can select at most 1 item(s)"
+        );
+    }
 }
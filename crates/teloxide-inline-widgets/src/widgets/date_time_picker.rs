@@ -0,0 +1,398 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{NestedWidget, Size, WidgetStyles},
+    widgets::{Calendar, CalendarSchemaParameters, TimePicker, TimePickerSchemaParameters},
+};
+
+/// The step of the [`DateTimePicker`] widget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DateTimePickerStep {
+    /// The [`Calendar`] is displayed, the clicked day switches the widget to
+    /// the [`DateTimePickerStep::Time`]
+    #[default]
+    Date,
+    /// The [`TimePicker`] is displayed with the button returning to the
+    /// [`DateTimePickerStep::Date`]
+    Time,
+}
+
+/// Date and time picker widget
+///
+/// Combines the [`Calendar`] and the [`TimePicker`] widgets, which are
+/// displayed one after another within the same keyboard
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DateTimePicker {
+    calendar: Calendar,
+    time_picker: TimePicker,
+    step: DateTimePickerStep,
+    /// Offset from UTC in seconds, the selected date and time are local to it
+    utc_offset: Option<i32>,
+}
+
+impl Default for DateTimePicker {
+    fn default() -> Self {
+        Self::new(Calendar::new(), TimePicker::default())
+    }
+}
+
+impl DateTimePicker {
+    /// Creates new [`DateTimePicker`] instance from the [`Calendar`] and the
+    /// [`TimePicker`] widgets
+    ///
+    /// The widget starts with the [`DateTimePickerStep::Date`]
+    pub fn new(calendar: Calendar, time_picker: TimePicker) -> Self {
+        Self { calendar, time_picker, step: DateTimePickerStep::Date, utc_offset: None }
+    }
+
+    /// Returns the reference to the [`Calendar`] widget
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Returns the mutable reference to the [`Calendar`] widget
+    pub fn calendar_mut(&mut self) -> &mut Calendar {
+        &mut self.calendar
+    }
+
+    /// Returns the reference to the [`TimePicker`] widget
+    pub fn time_picker(&self) -> &TimePicker {
+        &self.time_picker
+    }
+
+    /// Returns the mutable reference to the [`TimePicker`] widget
+    pub fn time_picker_mut(&mut self) -> &mut TimePicker {
+        &mut self.time_picker
+    }
+
+    /// Returns the current step of the widget
+    pub fn step(&self) -> DateTimePickerStep {
+        self.step
+    }
+
+    /// Sets the current step of the widget
+    pub fn set_step(&mut self, step: DateTimePickerStep) {
+        self.step = step;
+    }
+
+    /// Returns the time zone offset the selected date and time are local to
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        self.utc_offset.and_then(FixedOffset::east_opt)
+    }
+
+    /// Sets the time zone offset the selected date and time are local to
+    pub fn set_utc_offset(&mut self, utc_offset: Option<FixedOffset>) {
+        self.utc_offset = utc_offset.map(|utc_offset| utc_offset.local_minus_utc());
+    }
+
+    /// Returns the selected date and time if the date is selected
+    pub fn date_time(&self) -> Option<NaiveDateTime> {
+        Some(self.calendar.selected_date()?.and_time(self.time_picker.time()))
+    }
+
+    /// Returns the selected date and time in the time zone with the
+    /// `utc_offset`, UTC is used if the offset isn't set
+    pub fn date_time_with_offset(&self) -> Option<DateTime<FixedOffset>> {
+        let utc_offset = self.utc_offset().unwrap_or(FixedOffset::east_opt(0)?);
+        self.date_time()?.and_local_timezone(utc_offset).single()
+    }
+
+    /// Selects the date and switches to the [`DateTimePickerStep::Time`]
+    pub fn select_date(&mut self, date: NaiveDate) {
+        self.calendar.set_selected_date(Some(date));
+        self.step = DateTimePickerStep::Time;
+    }
+
+    /// [`dptree`]-schema for the [`DateTimePicker`] widget
+    ///
+    /// The `on_confirm` handler is invoked when the confirm button of the
+    /// [`TimePicker`] is clicked. It receives the selected [`NaiveDateTime`]
+    /// and [`DateTime<FixedOffset>`] and is supposed to answer the callback
+    /// query, like the [`Button`](crate::Button) handler
+    pub fn schema<W>(
+        parameters: &'static DateTimePickerSchemaParameters,
+        on_confirm: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_confirm = on_confirm.map(|on_confirm| {
+            dptree::filter_map(|mut widget: W| widget.get_widget().date_time())
+                .filter_map(|mut widget: W| widget.get_widget().date_time_with_offset())
+                .chain(on_confirm)
+        });
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    NaiveDate::parse_from_str(
+                        cq.data?.strip_prefix(parameters.calendar.day_prefix)?,
+                        "%Y/%m/%d",
+                    )
+                    .ok()
+                })
+                .branch(
+                    dptree::filter(move |mut widget: W, date: NaiveDate| {
+                        widget.get_widget().calendar.is_day_disabled(date, &parameters.calendar)
+                    })
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the disabled day");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .map(|mut widget: W, date: NaiveDate| {
+                    widget.get_widget().select_date(date);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
+            )
+            .branch(
+                dptree::filter(move |cq: CallbackQuery| {
+                    cq.data.as_deref() == Some(parameters.back_data)
+                })
+                .map(|mut widget: W| {
+                    widget.get_widget().set_step(DateTimePickerStep::Date);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
+            )
+            .branch(
+                dptree::entry()
+                    .map(|widget: W| NestedWidget::<W, Self>::new(widget))
+                    .branch(Calendar::schema::<NestedWidget<W, Self>>(
+                        &parameters.calendar,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .branch(TimePicker::schema::<NestedWidget<W, Self>>(
+                        &parameters.time_picker,
+                        on_confirm,
+                    )),
+            )
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`DateTimePicker`] widget
+    /// according to the current step
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &DateTimePickerSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        match (self.step, self.calendar.selected_date()) {
+            (DateTimePickerStep::Time, Some(date)) => {
+                let mut keyboard = vec![vec![InlineKeyboardButton::callback(
+                    date.format(&styles.date_time_picker_style.date_format).to_string(),
+                    parameters.back_data,
+                )]];
+                keyboard.extend(
+                    self.time_picker
+                        .inline_keyboard_markup(&parameters.time_picker, styles)
+                        .inline_keyboard,
+                );
+
+                InlineKeyboardMarkup::new(keyboard)
+            }
+            _ => self.calendar.inline_keyboard_markup(&parameters.calendar, styles),
+        }
+    }
+}
+
+impl WidgetContainer<Calendar> for DateTimePicker {
+    fn get_widget(&mut self) -> &mut Calendar {
+        &mut self.calendar
+    }
+}
+
+impl WidgetContainer<TimePicker> for DateTimePicker {
+    fn get_widget(&mut self) -> &mut TimePicker {
+        &mut self.time_picker
+    }
+}
+
+impl GetSize for DateTimePicker {
+    fn size(&self) -> Size {
+        match (self.step, self.calendar.selected_date()) {
+            (DateTimePickerStep::Time, Some(_)) => {
+                let Size { rows, columns } = self.time_picker.size();
+                Size { rows: rows + 1, columns }
+            }
+            _ => self.calendar.size(),
+        }
+    }
+}
+
+pub struct DateTimePickerSchemaParameters {
+    pub calendar: CalendarSchemaParameters,
+    pub time_picker: TimePickerSchemaParameters,
+    /// CallbackQuery data to be sent when the button with the selected date is
+    /// clicked
+    pub back_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use teloxide::types::InlineKeyboardButtonKind;
+
+    use super::*;
+
+    fn parameters() -> DateTimePickerSchemaParameters {
+        DateTimePickerSchemaParameters {
+            calendar: CalendarSchemaParameters {
+                day_prefix: "dt_d_",
+                weekday_prefix: "dt_w_",
+                previous_year_data: "dt_py",
+                next_year_data: "dt_ny",
+                previous_month_data: "dt_pm",
+                next_month_data: "dt_nm",
+                noop_data: "noop",
+                month_prefix: "dt_m_",
+                year_prefix: "dt_y_",
+                days_view_data: "dt_dv",
+                months_view_data: "dt_mv",
+                years_view_data: "dt_yv",
+                previous_years_page_data: "dt_pyp",
+                next_years_page_data: "dt_nyp",
+                is_day_disabled: None,
+            },
+            time_picker: TimePickerSchemaParameters { prefix: "dt_t_", noop_data: "noop" },
+            back_data: "dt_b",
+        }
+    }
+
+    fn callback_data(button: &InlineKeyboardButton) -> &str {
+        match &button.kind {
+            InlineKeyboardButtonKind::CallbackData(data) => data,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn date_time() {
+        let mut date_time_picker = DateTimePicker::new(
+            Calendar::with_ym(2024, 7),
+            TimePicker::new(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+        );
+        assert_eq!(date_time_picker.date_time(), None);
+
+        let date = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+        date_time_picker.select_date(date);
+        assert_eq!(date_time_picker.step(), DateTimePickerStep::Time);
+        assert_eq!(date_time_picker.size(), Size::new(5, 3));
+        assert_eq!(date_time_picker.date_time(), Some(date.and_hms_opt(9, 30, 0).unwrap()));
+
+        date_time_picker.set_utc_offset(FixedOffset::east_opt(3 * 3600));
+        assert_eq!(
+            date_time_picker.date_time_with_offset().unwrap().to_rfc3339(),
+            "2024-07-03T09:30:00+03:00"
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let mut date_time_picker = DateTimePicker::new(
+            Calendar::with_ym(2024, 7),
+            TimePicker::new(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+        );
+        let (parameters, styles) = (parameters(), WidgetStyles::default());
+        let calendar_keyboard = |date_time_picker: &DateTimePicker| {
+            date_time_picker.calendar().inline_keyboard_markup(&parameters.calendar, &styles)
+        };
+
+        // The calendar is displayed until the date is selected, even if the step is
+        // changed directly
+        assert_eq!(date_time_picker.step(), DateTimePickerStep::Date);
+        date_time_picker.set_step(DateTimePickerStep::Time);
+        assert_eq!(
+            date_time_picker.inline_keyboard_markup(&parameters, &styles),
+            calendar_keyboard(&date_time_picker)
+        );
+        assert_eq!(date_time_picker.size(), date_time_picker.calendar().size());
+
+        // Date -> Time: the clicked date is displayed above the time picker and leads
+        // back to the calendar
+        let date = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+        date_time_picker.select_date(date);
+        assert_eq!(date_time_picker.step(), DateTimePickerStep::Time);
+        let keyboard =
+            date_time_picker.inline_keyboard_markup(&parameters, &styles).inline_keyboard;
+        assert_eq!(keyboard.len() as u8, date_time_picker.size().rows);
+        assert_eq!(keyboard[0][0].text, "📅 2024-07-03");
+        assert_eq!(callback_data(&keyboard[0][0]), "dt_b");
+
+        // Time -> confirm: the confirm button of the time picker completes the
+        // selection
+        let confirm_button = &keyboard.last().unwrap()[0];
+        assert_eq!(confirm_button.text, "Confirm");
+        assert_eq!(callback_data(confirm_button), "dt_t_ok");
+        assert_eq!(date_time_picker.date_time(), Some(date.and_hms_opt(9, 30, 0).unwrap()));
+
+        // Back: the calendar is displayed again, the selected date is kept
+        date_time_picker.set_step(DateTimePickerStep::Date);
+        assert_eq!(
+            date_time_picker.inline_keyboard_markup(&parameters, &styles),
+            calendar_keyboard(&date_time_picker)
+        );
+        assert_eq!(date_time_picker.calendar().selected_date(), Some(date));
+
+        // The other date may be selected after returning
+        let date = NaiveDate::from_ymd_opt(2024, 7, 10).unwrap();
+        date_time_picker.select_date(date);
+        assert_eq!(date_time_picker.step(), DateTimePickerStep::Time);
+        assert_eq!(date_time_picker.date_time(), Some(date.and_hms_opt(9, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn utc_offset() {
+        let mut date_time_picker = DateTimePicker::new(
+            Calendar::with_ym(2024, 7),
+            TimePicker::new(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+        );
+        assert_eq!(date_time_picker.date_time_with_offset(), None);
+        date_time_picker.select_date(NaiveDate::from_ymd_opt(2024, 7, 3).unwrap());
+
+        // UTC is used if the offset isn't set
+        assert_eq!(date_time_picker.utc_offset(), None);
+        assert_eq!(
+            date_time_picker.date_time_with_offset().unwrap().to_rfc3339(),
+            "2024-07-03T09:30:00+00:00"
+        );
+
+        // The selected date and time are local to the offset
+        let utc_offset = FixedOffset::west_opt(5 * 3600 + 30 * 60);
+        date_time_picker.set_utc_offset(utc_offset);
+        assert_eq!(date_time_picker.utc_offset(), utc_offset);
+        let date_time = date_time_picker.date_time_with_offset().unwrap();
+        assert_eq!(date_time.to_rfc3339(), "2024-07-03T09:30:00-05:30");
+        assert_eq!(date_time.naive_utc().to_string(), "2024-07-03 15:00:00");
+        // The naive date and time aren't affected by the offset
+        assert_eq!(date_time_picker.date_time().unwrap().to_string(), "2024-07-03 09:30:00");
+
+        date_time_picker.set_utc_offset(None);
+        assert_eq!(
+            date_time_picker.date_time_with_offset().unwrap().to_rfc3339(),
+            "2024-07-03T09:30:00+00:00"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    types::{CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{NestedWidget, Size, WidgetStyles},
+    widgets::{RadioList, RadioListItemIndex, RadioListSchemaParameters},
+};
+
+/// Dropdown widget
+///
+/// Is displayed as the single button with the active item. The click on it
+/// expands the [`RadioList`] with the items beneath, which collapses again
+/// when the item is selected
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Dropdown<T> {
+    radio_list: RadioList<T>,
+    is_expanded: bool,
+}
+
+impl<T> Dropdown<T> {
+    /// Creates new collapsed [`Dropdown`] instance from the [`RadioList`]
+    /// widget
+    pub fn new(radio_list: RadioList<T>) -> Self {
+        Self { radio_list, is_expanded: false }
+    }
+
+    /// Returns the reference to the [`RadioList`] widget
+    pub fn radio_list(&self) -> &RadioList<T> {
+        &self.radio_list
+    }
+
+    /// Returns the mutable reference to the [`RadioList`] widget
+    pub fn radio_list_mut(&mut self) -> &mut RadioList<T> {
+        &mut self.radio_list
+    }
+
+    /// Returns the reference to the active item
+    pub fn active_item(&self) -> Option<&T> {
+        self.radio_list.active_item()
+    }
+
+    /// Returns `true` if the item list is displayed
+    pub fn is_expanded(&self) -> bool {
+        self.is_expanded
+    }
+
+    /// Expands or collapses the item list
+    pub fn set_expanded(&mut self, is_expanded: bool) {
+        self.is_expanded = is_expanded;
+    }
+
+    /// Selects the item by index and collapses the item list
+    ///
+    /// Panics if the index is out of bounds
+    pub fn select(&mut self, i: usize) {
+        self.radio_list.set_active(i);
+        self.is_expanded = false;
+    }
+
+    /// [`dptree`]-schema for the [`Dropdown`] widget
+    ///
+    /// The `on_change` handler is invoked after the new item is selected and
+    /// the widget is redrawn. It receives the updated `W` widget and the
+    /// [`RadioListItemIndex`] of the selected item, like the [`RadioList`]
+    /// handler
+    pub fn schema<W>(
+        parameters: &'static DropdownSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        T: 'static + Clone + Send + Sync,
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .branch(
+                dptree::filter(move |cq: CallbackQuery| {
+                    cq.data.as_deref() == Some(parameters.toggle_data)
+                })
+                .map(|mut widget: W| {
+                    let dropdown: &mut Self = widget.get_widget();
+                    dropdown.is_expanded = !dropdown.is_expanded;
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
+            )
+            .filter(|mut widget: W| widget.get_widget().is_expanded)
+            .branch(
+                dptree::filter_map(move |mut widget: W, cq: CallbackQuery| {
+                    let i = cq.data?.strip_prefix(parameters.radio_list.prefix)?.parse().ok()?;
+                    (i < widget.get_widget().radio_list.items().len())
+                        .then_some(RadioListItemIndex(i))
+                })
+                .branch(
+                    // The click on the already active item just collapses the list
+                    dptree::filter(|mut widget: W, RadioListItemIndex(i): RadioListItemIndex| {
+                        widget.get_widget().radio_list.active_item_i() == Some(i)
+                    })
+                    .map(|mut widget: W| {
+                        widget.get_widget().set_expanded(false);
+                        widget
+                    })
+                    .chain(update_widget::<W>())
+                    .endpoint(|| async { Ok(()) }),
+                )
+                .map(|mut widget: W, RadioListItemIndex(i): RadioListItemIndex| {
+                    widget.get_widget().select(i);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .branch(on_change)
+                .endpoint(|| async { Ok(()) }),
+            )
+            // The pagination is handled by the `RadioList` itself
+            .map(|widget: W| NestedWidget::<W, Self>::new(widget))
+            .branch(RadioList::<T>::schema::<NestedWidget<W, Self>>(&parameters.radio_list, None))
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Dropdown`] widget, which
+    /// is the button with the active item and the expanded item list
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &DropdownSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup
+    where
+        T: Display,
+    {
+        let dropdown_style = &styles.dropdown_style;
+        let icon = if self.is_expanded {
+            &dropdown_style.expanded_icon
+        } else {
+            &dropdown_style.collapsed_icon
+        };
+        let active_item = self
+            .active_item()
+            .map_or(dropdown_style.placeholder.to_string(), |item| item.to_string());
+
+        let mut keyboard = vec![vec![InlineKeyboardButton::callback(
+            format!("{icon} {active_item}"),
+            parameters.toggle_data,
+        )]];
+        if self.is_expanded {
+            keyboard.extend(
+                self.radio_list
+                    .inline_keyboard_markup(&parameters.radio_list, styles)
+                    .inline_keyboard,
+            );
+        }
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl<T> From<Vec<T>> for Dropdown<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(RadioList::from(value))
+    }
+}
+
+impl<T> WidgetContainer<RadioList<T>> for Dropdown<T> {
+    fn get_widget(&mut self) -> &mut RadioList<T> {
+        &mut self.radio_list
+    }
+}
+
+impl<T> GetSize for Dropdown<T> {
+    fn size(&self) -> Size {
+        if self.is_expanded {
+            let Size { rows, columns } = self.radio_list.size();
+            Size { rows: rows + 1, columns: columns.max(1) }
+        } else {
+            Size { rows: 1, columns: 1 }
+        }
+    }
+}
+
+pub struct DropdownSchemaParameters {
+    pub radio_list: RadioListSchemaParameters,
+    /// CallbackQuery data to be sent when the button with the active item is
+    /// clicked
+    pub toggle_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_and_select() {
+        let mut dropdown = Dropdown::from(vec!["a", "b", "c"]);
+        assert_eq!(dropdown.size(), Size::new(1, 1));
+
+        let parameters = DropdownSchemaParameters {
+            radio_list: RadioListSchemaParameters {
+                prefix: "d_",
+                page_prefix: "d_p_",
+                noop_data: "noop",
+            },
+            toggle_data: "d_t",
+        };
+        let styles = WidgetStyles::default();
+        let keyboard = dropdown.inline_keyboard_markup(&parameters, &styles).inline_keyboard;
+        assert_eq!(keyboard.len(), 1);
+        assert_eq!(keyboard[0][0].text, "🔽 —");
+
+        dropdown.set_expanded(true);
+        assert_eq!(dropdown.size(), Size::new(2, 3));
+        let keyboard = dropdown.inline_keyboard_markup(&parameters, &styles).inline_keyboard;
+        assert_eq!(keyboard.len(), 2);
+        assert_eq!(keyboard[0][0].text, "🔼 —");
+
+        dropdown.select(1);
+        assert!(!dropdown.is_expanded());
+        assert_eq!(dropdown.active_item(), Some(&"b"));
+    }
+}
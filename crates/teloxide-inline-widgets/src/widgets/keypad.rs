@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Numeric keypad widget
+///
+/// The typed digits are accumulated in the widget and are displayed in the
+/// header button, optionally masked
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Keypad {
+    input: String,
+    /// Maximal number of the typed digits
+    max_length: Option<usize>,
+    /// Whether the typed digits are hidden behind the mask icon
+    is_masked: bool,
+}
+
+/// Input of a [`Keypad`] widget. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone)]
+pub struct KeypadInput(pub String);
+
+/// Action performed by a [`Keypad`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeypadAction {
+    Digit(char),
+    Backspace,
+    Confirm,
+}
+
+impl KeypadAction {
+    /// Returns the part of the callback query data following the prefix
+    fn data(self) -> String {
+        match self {
+            Self::Digit(digit) => digit.to_string(),
+            Self::Backspace => "bs".to_owned(),
+            Self::Confirm => "ok".to_owned(),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        let action = match data {
+            "bs" => Self::Backspace,
+            "ok" => Self::Confirm,
+            _ => {
+                let mut chars = data.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(digit), None) if digit.is_ascii_digit() => Self::Digit(digit),
+                    _ => return None,
+                }
+            }
+        };
+
+        Some(action)
+    }
+}
+
+impl Keypad {
+    /// Creates new empty [`Keypad`] instance with optional limit of the
+    /// typed digits
+    pub fn new(max_length: Option<usize>) -> Self {
+        Self { input: String::new(), max_length, is_masked: false }
+    }
+
+    /// Returns the typed digits
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Sets the typed digits
+    ///
+    /// Panics if the input contains anything but digits or is longer than the
+    /// `max_length`
+    pub fn set_input(&mut self, input: &str) {
+        assert!(input.chars().all(|c| c.is_ascii_digit()));
+        assert!(self.max_length.map_or(true, |max_length| input.len() <= max_length));
+
+        self.input = input.to_owned();
+    }
+
+    /// Removes all the typed digits
+    pub fn clear(&mut self) {
+        self.input.clear();
+    }
+
+    /// Returns the maximal number of the typed digits
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Sets the maximal number of the typed digits, the excessive digits are
+    /// removed
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        if let Some(max_length) = max_length {
+            self.input.truncate(max_length);
+        }
+        self.max_length = max_length;
+    }
+
+    /// Returns `true` if the typed digits are masked
+    pub fn is_masked(&self) -> bool {
+        self.is_masked
+    }
+
+    /// Sets whether the typed digits are masked, e.g. for the one-time codes
+    pub fn set_masked(&mut self, is_masked: bool) {
+        self.is_masked = is_masked;
+    }
+
+    /// Returns `true` if no more digits can be typed
+    pub fn is_full(&self) -> bool {
+        self.max_length.is_some_and(|max_length| self.input.len() >= max_length)
+    }
+
+    /// Returns `true` if the `action` changes the input
+    fn can_apply(&self, action: KeypadAction) -> bool {
+        match action {
+            KeypadAction::Digit(_) => !self.is_full(),
+            KeypadAction::Backspace => !self.input.is_empty(),
+            KeypadAction::Confirm => false,
+        }
+    }
+
+    fn apply(&mut self, action: KeypadAction) {
+        match action {
+            KeypadAction::Digit(digit) => self.input.push(digit),
+            KeypadAction::Backspace => {
+                self.input.pop();
+            }
+            KeypadAction::Confirm => {}
+        }
+    }
+
+    /// [`dptree`]-schema for the [`Keypad`] widget
+    ///
+    /// The `on_confirm` handler is invoked when the confirm button is clicked.
+    /// It receives the typed [`KeypadInput`] and is supposed to answer the
+    /// callback query, like the [`Button`](crate::Button) handler
+    pub fn schema<W>(
+        parameters: &'static KeypadSchemaParameters,
+        on_confirm: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_confirm = on_confirm.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                KeypadAction::from_data(cq.data?.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|action: KeypadAction| action == KeypadAction::Confirm)
+                    .map(|mut widget: W| KeypadInput(widget.get_widget().input.clone()))
+                    .branch(on_confirm),
+            )
+            .branch(
+                dptree::filter(|mut widget: W, action: KeypadAction| {
+                    !widget.get_widget().can_apply(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, action: KeypadAction| {
+                widget.get_widget().apply(action);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Keypad`] widget with
+    /// specified callback query `prefix`
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &KeypadSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let keypad_style = &styles.keypad_style;
+        let button = |text: &str, action: KeypadAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+        let digit_button = |digit: char| button(&digit.to_string(), KeypadAction::Digit(digit));
+
+        let header = if self.input.is_empty() {
+            keypad_style.empty_input_text.to_string()
+        } else if self.is_masked {
+            keypad_style.mask_icon.repeat(self.input.len())
+        } else {
+            self.input.clone()
+        };
+
+        let mut keyboard = vec![vec![InlineKeyboardButton::callback(header, parameters.noop_data)]];
+        keyboard.extend(
+            ['1', '2', '3', '4', '5', '6', '7', '8', '9']
+                .chunks(3)
+                .map(|row| row.iter().copied().map(digit_button).collect()),
+        );
+        keyboard.push(vec![
+            button(&keypad_style.backspace_icon, KeypadAction::Backspace),
+            digit_button('0'),
+            button(&keypad_style.confirm_icon, KeypadAction::Confirm),
+        ]);
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl GetSize for Keypad {
+    fn size(&self) -> Size {
+        Size { rows: 5, columns: 3 }
+    }
+}
+
+pub struct KeypadSchemaParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input() {
+        let mut keypad = Keypad::new(Some(2));
+        for action in [KeypadAction::Digit('4'), KeypadAction::Digit('2')] {
+            assert!(keypad.can_apply(action));
+            keypad.apply(action);
+        }
+        assert_eq!(keypad.input(), "42");
+        assert!(!keypad.can_apply(KeypadAction::Digit('0')));
+
+        keypad.apply(KeypadAction::Backspace);
+        assert_eq!(keypad.input(), "4");
+        keypad.clear();
+        assert!(!keypad.can_apply(KeypadAction::Backspace));
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_digit_input() {
+        Keypad::default().set_input("4a");
+    }
+
+    #[test]
+    fn masked() {
+        let mut keypad = Keypad::default();
+        keypad.set_input("1234");
+        keypad.set_masked(true);
+
+        let parameters = KeypadSchemaParameters { prefix: "k_", noop_data: "noop" };
+        let keyboard =
+            keypad.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "••••");
+        assert_eq!(keyboard.len(), 5);
+        assert_eq!(KeypadAction::from_data("7"), Some(KeypadAction::Digit('7')));
+        assert_eq!(KeypadAction::from_data("77"), None);
+    }
+}
@@ -0,0 +1,409 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Node of the [`Menu`] tree
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum MenuNode<T> {
+    /// Opens the nested level of the menu
+    Submenu { label: String, children: Vec<MenuNode<T>> },
+    /// Leaf action, its value is passed to the `on_select` handler
+    Item { label: String, value: T },
+}
+
+impl<T> MenuNode<T> {
+    /// Creates the [`MenuNode::Submenu`] node
+    pub fn submenu(label: impl Into<String>, children: impl IntoIterator<Item = Self>) -> Self {
+        Self::Submenu { label: label.into(), children: children.into_iter().collect() }
+    }
+
+    /// Creates the [`MenuNode::Item`] node
+    pub fn item(label: impl Into<String>, value: T) -> Self {
+        Self::Item { label: label.into(), value }
+    }
+
+    /// Returns the label of the node
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Submenu { label, .. } | Self::Item { label, .. } => label,
+        }
+    }
+}
+
+/// Hierarchical menu widget
+///
+/// Displays the nodes of the current level, one per row. The submenus are
+/// opened in place, the path to the current level is stored in the widget
+/// and is displayed as the breadcrumb above the nodes
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Menu<T> {
+    nodes: Vec<MenuNode<T>>,
+    /// Indices of the opened submenus
+    path: Vec<usize>,
+}
+
+/// Action performed by a [`Menu`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+///
+/// The actions hold the path of the level they were displayed on, so the
+/// buttons of the stale keyboards aren't applied to the other levels
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MenuAction {
+    /// Clicks the node with the specified path, the last index is the index
+    /// of the node within its level
+    Open(Vec<usize>),
+    /// Returns to the parent level of the level with the specified path
+    Back(Vec<usize>),
+}
+
+impl MenuAction {
+    /// Returns the part of the callback query data following the prefix
+    fn data(&self) -> String {
+        let join = |path: &[usize]| path.iter().map(usize::to_string).collect::<Vec<_>>().join("_");
+        match self {
+            Self::Open(path) => join(path),
+            Self::Back(path) => format!("b{}", join(path)),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        let parse =
+            |data: &str| data.split('_').map(|i| i.parse().ok()).collect::<Option<Vec<_>>>();
+        match data.strip_prefix('b') {
+            Some(path) => Some(Self::Back(parse(path)?)),
+            None => Some(Self::Open(parse(data)?)),
+        }
+    }
+
+    /// Returns the path of the level the action was displayed on
+    fn level(&self) -> &[usize] {
+        match self {
+            Self::Open(path) => path.split_last().map_or(&[], |(_, level)| level),
+            Self::Back(path) => path,
+        }
+    }
+}
+
+impl<T> Menu<T> {
+    /// Creates new [`Menu`] instance with the root level `nodes`
+    pub fn new(nodes: impl IntoIterator<Item = MenuNode<T>>) -> Self {
+        Self { nodes: nodes.into_iter().collect(), path: vec![] }
+    }
+
+    /// Returns the indices of the opened submenus
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Returns to the root level
+    pub fn reset(&mut self) {
+        self.path.clear();
+    }
+
+    /// Returns the nodes of the current level
+    pub fn current_nodes(&self) -> &[MenuNode<T>] {
+        self.opened_submenus().last().map_or(&self.nodes, |(_, children)| children)
+    }
+
+    /// Returns the labels of the opened submenus
+    pub fn breadcrumb(&self) -> Vec<&str> {
+        self.opened_submenus().into_iter().map(|(label, _)| label).collect()
+    }
+
+    fn opened_submenus(&self) -> Vec<(&str, &[MenuNode<T>])> {
+        let mut nodes = &self.nodes;
+        let mut submenus = vec![];
+        for &i in &self.path {
+            match nodes.get(i) {
+                Some(MenuNode::Submenu { label, children }) => {
+                    submenus.push((label.as_str(), children.as_slice()));
+                    nodes = children;
+                }
+                _ => break,
+            }
+        }
+        submenus
+    }
+
+    /// Opens the submenu of the current level with the specified index,
+    /// `false` is returned if there is no such submenu
+    pub fn open(&mut self, i: usize) -> bool {
+        let can_open = matches!(self.current_nodes().get(i), Some(MenuNode::Submenu { .. }));
+        if can_open {
+            self.path.push(i);
+        }
+        can_open
+    }
+
+    /// Returns to the parent level, `false` is returned on the root level
+    pub fn back(&mut self) -> bool {
+        self.path.pop().is_some()
+    }
+
+    /// Checks whether the `action` was displayed on the current level, the
+    /// stale buttons of the previous keyboards can be clicked as well
+    fn is_current_level(&self, action: &MenuAction) -> bool {
+        action.level() == self.path
+    }
+
+    /// Checks whether the `action` opens the submenu or returns to the parent
+    /// level
+    fn can_apply(&self, action: &MenuAction) -> bool {
+        match action {
+            MenuAction::Open(path) => matches!(
+                path.last().and_then(|&i| self.current_nodes().get(i)),
+                Some(MenuNode::Submenu { .. })
+            ),
+            MenuAction::Back(_) => !self.path.is_empty(),
+        }
+    }
+
+    /// [`dptree`]-schema for the [`Menu`] widget
+    ///
+    /// The `on_select` handler is invoked when the [`MenuNode::Item`] is
+    /// clicked. It receives the value of the item of the `T` type and is
+    /// supposed to answer the callback query, like the
+    /// [`Button`](crate::Button) handler
+    pub fn schema<W>(
+        parameters: &'static MenuSchemaParameters,
+        on_select: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        T: 'static + Clone + Send + Sync,
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_select = on_select.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                MenuAction::from_data(cq.data?.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|mut widget: W, action: MenuAction| {
+                    !widget.get_widget().is_current_level(&action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the button of the other menu level");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter_map(|mut widget: W, action: MenuAction| match action {
+                    MenuAction::Open(path) => {
+                        match widget.get_widget().current_nodes().get(*path.last()?)? {
+                            MenuNode::Item { value, .. } => Some(value.clone()),
+                            MenuNode::Submenu { .. } => None,
+                        }
+                    }
+                    MenuAction::Back(_) => None,
+                })
+                .branch(on_select)
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter(|mut widget: W, action: MenuAction| {
+                    !widget.get_widget().can_apply(&action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the button which doesn't exist anymore");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, action: MenuAction| {
+                let menu: &mut Self = widget.get_widget();
+                match action {
+                    MenuAction::Open(path) => path.last().is_some_and(|&i| menu.open(i)),
+                    MenuAction::Back(_) => menu.back(),
+                };
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Menu`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// The breadcrumb and the `Back` button are displayed only on the nested
+    /// levels
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &MenuSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let menu_style = &styles.menu_style;
+        let button = |text: String, action: MenuAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+
+        let mut keyboard = vec![];
+        let breadcrumb = self.breadcrumb();
+        if !breadcrumb.is_empty() {
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                breadcrumb.join(&menu_style.breadcrumb_separator),
+                parameters.noop_data,
+            )]);
+        }
+        keyboard.extend(self.current_nodes().iter().enumerate().map(|(i, node)| {
+            let text = match node {
+                MenuNode::Submenu { label, .. } => {
+                    menu_style.submenu_format.replace("{label}", label)
+                }
+                MenuNode::Item { label, .. } => label.clone(),
+            };
+            vec![button(text, MenuAction::Open([self.path.as_slice(), &[i]].concat()))]
+        }));
+        if !breadcrumb.is_empty() {
+            keyboard.push(vec![button(
+                menu_style.back_text.to_string(),
+                MenuAction::Back(self.path.clone()),
+            )]);
+        }
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl<T> GetSize for Menu<T> {
+    fn size(&self) -> Size {
+        let navigation_rows = if self.path.is_empty() { 0 } else { 2 };
+        let rows = self.current_nodes().len().saturating_add(navigation_rows);
+        Size { rows: rows.try_into().unwrap_or(u8::MAX), columns: 1 }
+    }
+}
+
+pub struct MenuSchemaParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::{types::InlineKeyboardButtonKind, Bot};
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<MenuWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct MenuWidget {
+        #[menu(prefix = "m_", on_select = select)]
+        menu: Menu<u8>,
+    }
+
+    async fn select(
+        bot: Bot,
+        value: u8,
+        (chat_id, _, cq_id): (ChatId, MessageId, String),
+    ) -> Result<(), Error> {
+        bot.answer_callback_query(cq_id).await?;
+        bot.send_message(chat_id, value.to_string()).await?;
+
+        Ok(())
+    }
+
+    fn menu() -> Menu<u8> {
+        Menu::new([
+            MenuNode::submenu(
+                "Settings",
+                [
+                    MenuNode::submenu("Notifications", [MenuNode::item("Mute", 1)]),
+                    MenuNode::item("Language", 2),
+                ],
+            ),
+            MenuNode::item("Help", 3),
+        ])
+    }
+
+    #[test]
+    fn navigation() {
+        let mut menu = menu();
+        assert!(!menu.open(1));
+        assert!(!menu.back());
+
+        assert!(menu.open(0));
+        assert!(menu.open(0));
+        assert_eq!(menu.path(), [0, 0]);
+        assert_eq!(menu.breadcrumb(), ["Settings", "Notifications"]);
+        assert_eq!(menu.current_nodes()[0].label(), "Mute");
+        assert_eq!(menu.size(), Size::new(3, 1));
+
+        assert!(menu.back());
+        assert_eq!(menu.current_nodes().len(), 2);
+        menu.reset();
+        assert_eq!(menu.size(), Size::new(2, 1));
+    }
+
+    #[test]
+    fn markup() {
+        let mut menu = menu();
+        menu.open(0);
+        let parameters = MenuSchemaParameters { prefix: "m_", noop_data: "noop" };
+        let keyboard =
+            menu.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard.iter().map(|row| row[0].text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["Settings", "Notifications ›", "Language", "◀️ Back"]);
+        let data = keyboard
+            .iter()
+            .map(|row| match &row[0].kind {
+                InlineKeyboardButtonKind::CallbackData(data) => data.as_str(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(data, ["noop", "m_0_0", "m_0_1", "m_b0"]);
+    }
+
+    #[tokio::test]
+    async fn stale_levels() {
+        let mut widget = MenuWidget { menu: menu() };
+        widget.menu.open(0);
+
+        let dispatched = dispatch(widget.clone(), "m_0_1").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "SendMessage"]);
+        assert_eq!(dispatched.requests[1].body["text"], "2");
+
+        // The root level `Help` button isn't treated as the `Language` one
+        assert!(dispatch(widget.clone(), "m_1").await.is_ignored());
+        // The stale `Back` button of the nested level
+        assert!(dispatch(MenuWidget { menu: menu() }, "m_b0").await.is_ignored());
+        assert!(dispatch(widget.clone(), "m_0_2").await.is_ignored());
+        // Forged data isn't parsed
+        assert!(!dispatch(widget.clone(), "m_b").await.handled);
+        assert!(!dispatch(widget.clone(), "m_0_x").await.handled);
+
+        let dispatched = dispatch(widget, "m_b0").await;
+        assert!(dispatched.widget.unwrap().menu.path().is_empty());
+    }
+}
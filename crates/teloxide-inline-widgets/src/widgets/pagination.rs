@@ -0,0 +1,109 @@
+use teloxide::types::InlineKeyboardButton;
+
+use crate::types::{Size, WidgetStyles};
+
+/// Maximum number of the items in a row of a widget created from a [`Vec`]
+const DEFAULT_PAGE_COLUMNS: u8 = 4;
+
+/// Returns the size of a widget created from a [`Vec`] of `items_count` items.
+/// The items are displayed in a single row of up to [`DEFAULT_PAGE_COLUMNS`]
+/// items, the rest of them are split into pages
+pub(crate) fn default_size(items_count: usize) -> Size {
+    let columns = items_count.clamp(1, DEFAULT_PAGE_COLUMNS as usize);
+    Size::new(1, columns as u8)
+}
+
+/// Returns the number of pages required to display all the items of a widget
+/// with the specified `size`
+pub(crate) fn pages_count(items_count: usize, Size { rows, columns }: Size) -> usize {
+    let page_size = rows as usize * columns as usize;
+    if page_size == 0 {
+        return 1;
+    }
+
+    items_count.div_ceil(page_size).max(1)
+}
+
+/// Returns the size of a widget that (optionally) has the pagination row
+/// beneath the items
+///
+/// The pagination row consists of at least 3 buttons
+pub(crate) fn paginated_size(size: Size, pages_count: usize) -> Size {
+    if pages_count > 1 {
+        Size { rows: size.rows.saturating_add(1), columns: size.columns.max(3) }
+    } else {
+        size
+    }
+}
+
+/// Creates the row of the `previous page`, `page indicator` and `next page`
+/// buttons.
+///
+/// The callback query data of the navigation buttons is the `page_prefix`
+/// followed by the 0-based index of the target page
+pub(crate) fn pagination_row(
+    page: usize,
+    pages_count: usize,
+    page_prefix: &str,
+    noop_data: &str,
+    styles: &WidgetStyles,
+) -> Vec<InlineKeyboardButton> {
+    let common_style = &styles.common_style;
+
+    let previous_page_button = if page > 0 {
+        InlineKeyboardButton::callback(
+            common_style.previous_page_icon.clone(),
+            format!("{page_prefix}{}", page - 1),
+        )
+    } else {
+        InlineKeyboardButton::callback(common_style.empty_cell_icon.clone(), noop_data)
+    };
+    let next_page_button = if page + 1 < pages_count {
+        InlineKeyboardButton::callback(
+            common_style.next_page_icon.clone(),
+            format!("{page_prefix}{}", page + 1),
+        )
+    } else {
+        InlineKeyboardButton::callback(common_style.empty_cell_icon.clone(), noop_data)
+    };
+
+    vec![
+        previous_page_button,
+        InlineKeyboardButton::callback(format!("{}/{pages_count}", page + 1), noop_data),
+        next_page_button,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, Size::new(2, 2), 1)]
+    #[case(4, Size::new(2, 2), 1)]
+    #[case(5, Size::new(2, 2), 2)]
+    #[case(300, Size::new(5, 2), 30)]
+    #[case(3, Size::new(0, 0), 1)]
+    fn pages(#[case] items_count: usize, #[case] size: Size, #[case] expected: usize) {
+        assert_eq!(pages_count(items_count, size), expected);
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(paginated_size(Size::new(2, 2), 1), Size::new(2, 2));
+        assert_eq!(paginated_size(Size::new(2, 2), 2), Size::new(3, 3));
+        assert_eq!(paginated_size(Size::new(3, 4), 2), Size::new(4, 4));
+        assert_eq!(paginated_size(Size::new(u8::MAX, 1), 2), Size::new(u8::MAX, 3));
+    }
+
+    #[rstest]
+    #[case(0, Size::new(1, 1))]
+    #[case(2, Size::new(1, 2))]
+    #[case(256, Size::new(1, 4))]
+    #[case(300, Size::new(1, 4))]
+    fn default(#[case] items_count: usize, #[case] expected: Size) {
+        assert_eq!(default_size(items_count), expected);
+    }
+}
@@ -9,23 +9,38 @@ use teloxide::{
 };
 
 use crate::{
+    handlers::update_widget,
     traits::{GetSize, InlineWidget, WidgetContainer},
     types::{Size, WidgetStyles},
+    widgets::pagination::{default_size, pages_count, paginated_size, pagination_row},
 };
 
 /// Radio list widget
+///
+/// If the items don't fit within the `size`, the [`RadioList`] is split into
+/// pages with the navigation row beneath the items
 // FIXME add gif to docs?
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RadioList<T> {
+    /// Size of the items area of the [`RadioList`] widget (excluding the
+    /// pagination row)
     pub size: Size,
     items: Vec<T>,
     active_item_i: Option<usize>,
+    /// Currently displayed page, 0-based
+    #[serde(default)]
+    page: usize,
 }
 
 /// Index of a [`RadioList`] item. Used as a unique type in the
 /// [`dptree`]-handler schema
 #[derive(Debug, Clone)]
-struct RadioListItemIndex(pub usize);
+pub struct RadioListItemIndex(pub usize);
+
+/// Index of a [`RadioList`] page. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone)]
+struct RadioListPage(pub usize);
 
 impl<T> RadioList<T> {
     /// Creates new [`RadioList`] instance from a collection with optionally
@@ -47,7 +62,7 @@ impl<T> RadioList<T> {
             assert!(i < items.len());
         }
 
-        Self { items: Vec::from_iter(items), active_item_i, size }
+        Self { items: Vec::from_iter(items), active_item_i, size, page: 0 }
     }
 
     /// Returns the reference to the active item
@@ -74,53 +89,119 @@ impl<T> RadioList<T> {
         &self.items
     }
 
+    /// Returns the index of the currently displayed page
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages required to display all the items
+    pub fn pages_count(&self) -> usize {
+        pages_count(self.items.len(), self.size)
+    }
+
+    /// Sets the currently displayed page by index
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_page(&mut self, page: usize) {
+        assert!(page < self.pages_count());
+
+        self.page = page;
+    }
+
     // TODO more helpful functions
 
     // TODO Add tests
     /// [`dptree`]-schema for the [`RadioList`] widget
-    pub fn schema<W>(parameters: &'static RadioListSchemaParameters) -> UpdateHandler<W::Err>
+    ///
+    /// The `on_change` handler is invoked after the new item is selected and
+    /// the widget is redrawn. It receives the updated `W` widget and the
+    /// [`RadioListItemIndex`] of the selected item
+    pub fn schema<W>(
+        parameters: &'static RadioListSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
     where
         W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
         W::Bot: 'static + Clone + Send + Sync,
         W::Dialogue: 'static + Clone + Send + Sync,
     {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
         dptree::entry()
-            .filter_map(move |cq: CallbackQuery| {
-                Some(RadioListItemIndex(cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?))
-            })
             .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
-            .endpoint(
-                |bot: W::Bot,
-                 dialogue: W::Dialogue,
-                 mut widget: W,
-                 (chat_id, message_id, cq_id): (ChatId, MessageId, String),
-                 widget_styles: WidgetStyles,
-                 RadioListItemIndex(i): RadioListItemIndex| async move {
-                    bot.answer_callback_query(cq_id).await?;
-
-                    let rl = widget.get_widget();
-                    if rl.active_item_i == Some(i) {
-                        log::warn!("User clicked on the already selected radio button");
-                        return Ok(());
-                    }
-
-                    rl.set_active(i);
-                    // FIXME: Probably allow some callback here? Or after
-
-                    // It's safe to update the view (keyboard) before the state if updates are
-                    // processed consistently in a single chat, so there is no
-                    // races
-                    widget.redraw(&bot, chat_id, message_id, &widget_styles).await?;
-                    widget.update_state(&dialogue).await?;
-
-                    Ok(())
-                },
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    Some(RadioListItemIndex(
+                        cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?,
+                    ))
+                })
+                .branch(
+                    dptree::filter(|mut widget: W, RadioListItemIndex(i): RadioListItemIndex| {
+                        i >= widget.get_widget().items.len()
+                    })
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the RadioList item that doesn't exist");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .branch(
+                    dptree::filter(|mut widget: W, RadioListItemIndex(i): RadioListItemIndex| {
+                        widget.get_widget().active_item_i == Some(i)
+                    })
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the already selected radio button");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .map(|mut widget: W, RadioListItemIndex(i): RadioListItemIndex| {
+                    widget.get_widget().set_active(i);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .branch(on_change)
+                .endpoint(|| async { Ok(()) }),
+            )
+            .branch(
+                dptree::filter_map(move |cq: CallbackQuery| {
+                    Some(RadioListPage(
+                        cq.data?.strip_prefix(parameters.page_prefix)?.parse().ok()?,
+                    ))
+                })
+                .branch(
+                    dptree::filter(|mut widget: W, RadioListPage(page): RadioListPage| {
+                        page >= widget.get_widget().pages_count()
+                    })
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+                            log::warn!("User clicked on the RadioList page that doesn't exist");
+
+                            Ok(())
+                        },
+                    ),
+                )
+                .map(|mut widget: W, RadioListPage(page): RadioListPage| {
+                    widget.get_widget().set_page(page);
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
             )
     }
 
     /// Creates the [`InlineKeyboardMarkup`] for a [`RadioList`] widget with
     /// specified callback query `prefix` and size.
     ///
+    /// Only the items of the current page are displayed
+    ///
     /// It's not supposed to be used directly
     pub fn inline_keyboard_markup(
         &self,
@@ -144,9 +225,12 @@ impl<T> RadioList<T> {
         .take(rows as usize)
         .collect();
 
-        for (row_i, row_chunk) in self.items.chunks(columns as usize).enumerate() {
+        let page_size = rows as usize * columns as usize;
+        let page_offset = self.page * page_size;
+        let page_items = self.items.iter().skip(page_offset).take(page_size).collect::<Vec<_>>();
+        for (row_i, row_chunk) in page_items.chunks(columns.max(1) as usize).enumerate() {
             for (column_i, item) in row_chunk.iter().enumerate() {
-                let i = (row_i * columns as usize) + column_i;
+                let i = page_offset + (row_i * columns as usize) + column_i;
                 let icon = if self.active_item_i == Some(i) {
                     &styles.radio_list_style.active_icon
                 } else {
@@ -160,6 +244,17 @@ impl<T> RadioList<T> {
             }
         }
 
+        let pages_count = self.pages_count();
+        if pages_count > 1 {
+            keyboard.push(pagination_row(
+                self.page,
+                pages_count,
+                parameters.page_prefix,
+                parameters.noop_data,
+                styles,
+            ));
+        }
+
         InlineKeyboardMarkup::new(keyboard)
     }
 }
@@ -172,19 +267,22 @@ impl<T> FromIterator<T> for RadioList<T> {
 
 impl<T> From<Vec<T>> for RadioList<T> {
     fn from(value: Vec<T>) -> Self {
-        let size = Size::new(1, value.len() as u8);
+        let size = default_size(value.len());
         RadioList::new(value, None, size)
     }
 }
 
 impl<T> GetSize for RadioList<T> {
     fn size(&self) -> Size {
-        self.size
+        paginated_size(self.size, self.pages_count())
     }
 }
 
 pub struct RadioListSchemaParameters {
     pub prefix: &'static str,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: &'static str,
     pub noop_data: &'static str,
 }
 
@@ -213,4 +311,38 @@ mod tests {
 
         rl.set_active(3);
     }
+
+    #[test]
+    fn from_vec() {
+        let rl = RadioList::from((0..300).collect::<Vec<_>>());
+        assert_eq!(rl.size(), Size::new(2, 4));
+        assert_eq!(rl.pages_count(), 75);
+        assert_eq!(RadioList::from(vec![1, 2]).size(), Size::new(1, 2));
+    }
+
+    #[test]
+    fn pagination() {
+        let mut rl = RadioList::new(1..=5, None, Size::new(2, 2));
+        assert_eq!(rl.pages_count(), 2);
+        assert_eq!(rl.size(), Size::new(3, 3));
+
+        rl.set_page(1);
+        assert_eq!(rl.page(), 1);
+
+        let parameters =
+            RadioListSchemaParameters { prefix: "r_", page_prefix: "r_p_", noop_data: "noop" };
+        let keyboard =
+            rl.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        assert_eq!(keyboard.len(), 3);
+        assert_eq!(keyboard[0][0].text, " 5");
+        assert_eq!(keyboard[2].len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn page_out_of_bounds() {
+        let mut rl = RadioList::new(1..=5, None, Size::new(2, 2));
+
+        rl.set_page(2);
+    }
 }
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Star rating widget
+///
+/// A row of `max` stars, the click on the k-th star sets the rating to k
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Rating {
+    /// Number of stars
+    #[serde(default = "default_max")]
+    max: u8,
+    rating: Option<u8>,
+}
+
+/// Number of a clicked [`Rating`] star, 1-based. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone)]
+struct RatingStar(u8);
+
+impl Rating {
+    /// Creates new [`Rating`] instance with `max` stars and optionally set
+    /// rating
+    ///
+    /// Panics if the rating is out of bounds
+    pub fn new(max: u8, rating: Option<u8>) -> Self {
+        if max == 0 {
+            log::warn!("Rating has no stars");
+        }
+
+        let mut widget = Self { max, rating: None };
+        widget.set_rating(rating);
+        widget
+    }
+
+    /// Returns the number of stars
+    pub fn max(&self) -> u8 {
+        self.max
+    }
+
+    /// Returns the current rating
+    pub fn rating(&self) -> Option<u8> {
+        self.rating
+    }
+
+    /// Sets the current rating
+    ///
+    /// Panics if the rating is out of the `1..=max` bounds
+    pub fn set_rating(&mut self, rating: Option<u8>) {
+        if let Some(rating) = rating {
+            assert!((1..=self.max).contains(&rating));
+        }
+
+        self.rating = rating;
+    }
+
+    /// Returns the rating after the `star` is clicked
+    fn rating_after_click(&self, star: u8, parameters: &RatingSchemaParameters) -> Option<u8> {
+        if parameters.clearable && self.rating == Some(star) {
+            None
+        } else {
+            Some(star)
+        }
+    }
+
+    /// [`dptree`]-schema for the [`Rating`] widget
+    ///
+    /// The `on_change` handler is invoked after the rating is changed and the
+    /// widget is redrawn. It receives the updated `W` widget and the new
+    /// rating as `Option<u8>`
+    pub fn schema<W>(
+        parameters: &'static RatingSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |mut widget: W, cq: CallbackQuery| {
+                let star = cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?;
+                (1..=widget.get_widget().max).contains(&star).then_some(RatingStar(star))
+            })
+            .map(move |mut widget: W, RatingStar(star): RatingStar| {
+                widget.get_widget().rating_after_click(star, parameters)
+            })
+            .branch(
+                dptree::filter(|mut widget: W, rating: Option<u8>| {
+                    widget.get_widget().rating == rating
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the already selected rating");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, rating: Option<u8>| {
+                widget.get_widget().set_rating(rating);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .branch(on_change)
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Rating`] widget with
+    /// specified callback query `prefix`
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &RatingSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let rating = self.rating.unwrap_or(0);
+
+        InlineKeyboardMarkup::new(vec![(1..=self.max)
+            .map(|star| {
+                let icon = if star <= rating {
+                    &styles.rating_style.filled_icon
+                } else {
+                    &styles.rating_style.empty_icon
+                };
+                InlineKeyboardButton::callback(
+                    icon.clone(),
+                    format!("{}{}", parameters.prefix, star),
+                )
+            })
+            .collect::<Vec<_>>()])
+    }
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self::new(default_max(), None)
+    }
+}
+
+impl GetSize for Rating {
+    fn size(&self) -> Size {
+        Size { rows: 1, columns: self.max }
+    }
+}
+
+/// Returns the default number of stars, it's also used for the deserialized
+/// [`Rating`] without the `max`
+fn default_max() -> u8 {
+    5
+}
+
+pub struct RatingSchemaParameters {
+    /// CallbackQuery data prefix to be sent with the number of the clicked
+    /// star
+    pub prefix: &'static str,
+    /// Whether the click on the selected star clears the rating
+    pub clearable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rating() {
+        let mut parameters = RatingSchemaParameters { prefix: "r_", clearable: false };
+        let mut rating = Rating::new(5, Some(3));
+        assert_eq!(rating.rating_after_click(3, &parameters), Some(3));
+        parameters.clearable = true;
+        assert_eq!(rating.rating_after_click(3, &parameters), None);
+        assert_eq!(rating.rating_after_click(4, &parameters), Some(4));
+
+        rating.set_rating(Some(2));
+        let keyboard =
+            rating.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard[0].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["⭐", "⭐", "☆", "☆", "☆"]);
+        assert_eq!(rating.size(), Size::new(1, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rating_out_of_bounds() {
+        Rating::new(5, Some(6));
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Sortable list widget
+///
+/// The items are displayed one per row. The clicked item is selected and can
+/// be moved by the buttons of the control row beneath the items
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SortableList<T> {
+    items: Vec<T>,
+    selected_item_i: Option<usize>,
+}
+
+/// Action performed by a [`SortableList`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortableListAction {
+    /// Selects the item with the specified index, or deselects it if it's
+    /// already selected
+    Select(usize),
+    MoveUp,
+    MoveDown,
+    MoveToTop,
+}
+
+/// Index of the [`SortableList`] item to be selected. Used as a unique type in
+/// the [`dptree`]-handler schema
+#[derive(Debug, Clone)]
+struct SortableListItemIndex(usize);
+
+impl SortableListAction {
+    /// Returns the part of the callback query data following the prefix
+    fn data(self) -> String {
+        match self {
+            Self::Select(i) => i.to_string(),
+            Self::MoveUp => "u".to_owned(),
+            Self::MoveDown => "d".to_owned(),
+            Self::MoveToTop => "t".to_owned(),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        let action = match data {
+            "u" => Self::MoveUp,
+            "d" => Self::MoveDown,
+            "t" => Self::MoveToTop,
+            _ => Self::Select(data.parse().ok()?),
+        };
+
+        Some(action)
+    }
+}
+
+impl<T> SortableList<T> {
+    /// Creates new [`SortableList`] instance from a collection without the
+    /// selected item
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        Self { items: items.into_iter().collect(), selected_item_i: None }
+    }
+
+    /// Returns the items in the current order
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consumes the widget, returning the items in the current order
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns the index of the selected item
+    pub fn selected_item_i(&self) -> Option<usize> {
+        self.selected_item_i
+    }
+
+    /// Selects the item by index, `None` deselects the item
+    ///
+    /// Panics if the index is out of bounds
+    pub fn select(&mut self, i: Option<usize>) {
+        if let Some(i) = i {
+            assert!(i < self.items.len());
+        }
+
+        self.selected_item_i = i;
+    }
+
+    /// Returns the index of the selected item after the `action` is applied,
+    /// `None` is returned if the action is unavailable
+    fn target_i(&self, action: SortableListAction) -> Option<usize> {
+        let i = self.selected_item_i?;
+        match action {
+            SortableListAction::MoveUp => i.checked_sub(1),
+            SortableListAction::MoveDown => Some(i + 1).filter(|&i| i < self.items.len()),
+            SortableListAction::MoveToTop => Some(0).filter(|_| i > 0),
+            SortableListAction::Select(_) => None,
+        }
+    }
+
+    /// Checks whether the `action` can be performed, the stale buttons of the
+    /// previous keyboards can be clicked as well
+    fn can_apply(&self, action: SortableListAction) -> bool {
+        match action {
+            SortableListAction::Select(i) => i < self.items.len(),
+            _ => self.target_i(action).is_some(),
+        }
+    }
+
+    /// Moves the selected item to the `target_i` position, the item stays
+    /// selected
+    fn move_selected(&mut self, target_i: usize) {
+        let Some(i) = self.selected_item_i else { return };
+
+        let item = self.items.remove(i);
+        self.items.insert(target_i, item);
+        self.selected_item_i = Some(target_i);
+    }
+
+    /// Moves the selected item one position up
+    pub fn move_up(&mut self) {
+        if let Some(target_i) = self.target_i(SortableListAction::MoveUp) {
+            self.move_selected(target_i);
+        }
+    }
+
+    /// Moves the selected item one position down
+    pub fn move_down(&mut self) {
+        if let Some(target_i) = self.target_i(SortableListAction::MoveDown) {
+            self.move_selected(target_i);
+        }
+    }
+
+    /// Moves the selected item to the top of the list
+    pub fn move_to_top(&mut self) {
+        if let Some(target_i) = self.target_i(SortableListAction::MoveToTop) {
+            self.move_selected(target_i);
+        }
+    }
+
+    /// [`dptree`]-schema for the [`SortableList`] widget
+    ///
+    /// The `on_change` handler is invoked after the selected item is moved
+    /// and the widget is redrawn. It receives the updated `W` widget
+    pub fn schema<W>(
+        parameters: &'static SortableListSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                SortableListAction::from_data(cq.data?.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|mut widget: W, action: SortableListAction| {
+                    !widget.get_widget().can_apply(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the button which can't move the item");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter_map(|action: SortableListAction| match action {
+                    SortableListAction::Select(i) => Some(SortableListItemIndex(i)),
+                    _ => None,
+                })
+                .map(|mut widget: W, SortableListItemIndex(i): SortableListItemIndex| {
+                    let sortable_list: &mut Self = widget.get_widget();
+                    let is_selected = sortable_list.selected_item_i == Some(i);
+                    sortable_list.select((!is_selected).then_some(i));
+                    widget
+                })
+                .chain(update_widget::<W>())
+                .endpoint(|| async { Ok(()) }),
+            )
+            .map(|mut widget: W, action: SortableListAction| {
+                let sortable_list: &mut Self = widget.get_widget();
+                if let Some(target_i) = sortable_list.target_i(action) {
+                    sortable_list.move_selected(target_i);
+                }
+                widget
+            })
+            .chain(update_widget::<W>())
+            .branch(on_change)
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`SortableList`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// The control buttons which can't move the selected item are displayed as
+    /// empty cells
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &SortableListSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup
+    where
+        T: Display,
+    {
+        let sortable_list_style = &styles.sortable_list_style;
+        let button = |text: String, action: SortableListAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+        let control_button = |icon: &str, action: SortableListAction| {
+            if self.can_apply(action) {
+                button(icon.to_owned(), action)
+            } else {
+                InlineKeyboardButton::callback(
+                    styles.common_style.empty_cell_icon.clone(),
+                    parameters.noop_data,
+                )
+            }
+        };
+
+        let mut keyboard = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let text = if self.selected_item_i == Some(i) {
+                    format!("{} {item}", sortable_list_style.selected_icon)
+                } else {
+                    item.to_string()
+                };
+                vec![button(text, SortableListAction::Select(i))]
+            })
+            .collect::<Vec<_>>();
+        keyboard.push(vec![
+            control_button(&sortable_list_style.move_up_icon, SortableListAction::MoveUp),
+            control_button(&sortable_list_style.move_down_icon, SortableListAction::MoveDown),
+            control_button(&sortable_list_style.move_to_top_icon, SortableListAction::MoveToTop),
+        ]);
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl<T> From<Vec<T>> for SortableList<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> GetSize for SortableList<T> {
+    fn size(&self) -> Size {
+        Size { rows: (self.items.len() + 1).try_into().unwrap_or(u8::MAX), columns: 3 }
+    }
+}
+
+pub struct SortableListSchemaParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<SortableListWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct SortableListWidget {
+        #[sortable_list(prefix = "s_")]
+        sortable_list: SortableList<u8>,
+    }
+
+    #[test]
+    fn moving() {
+        let mut sortable_list = SortableList::from(vec![1, 2, 3, 4]);
+        sortable_list.move_up();
+        assert_eq!(sortable_list.items(), [1, 2, 3, 4]);
+
+        sortable_list.select(Some(2));
+        sortable_list.move_up();
+        assert_eq!(sortable_list.items(), [1, 3, 2, 4]);
+        assert_eq!(sortable_list.selected_item_i(), Some(1));
+
+        sortable_list.move_to_top();
+        assert_eq!(sortable_list.items(), [3, 1, 2, 4]);
+        assert!(!sortable_list.can_apply(SortableListAction::MoveUp));
+        assert!(!sortable_list.can_apply(SortableListAction::MoveToTop));
+
+        sortable_list.select(Some(3));
+        sortable_list.move_down();
+        assert_eq!(sortable_list.into_items(), [3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn markup() {
+        let mut sortable_list = SortableList::from(vec!["a", "b"]);
+        sortable_list.select(Some(1));
+        let parameters = SortableListSchemaParameters { prefix: "s_", noop_data: "noop" };
+        let keyboard = sortable_list
+            .inline_keyboard_markup(&parameters, &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard[1][0].text, "👉 b");
+        let texts = keyboard[2].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["⬆️", "✖️", "⏫"]);
+    }
+
+    #[tokio::test]
+    async fn stale_actions() {
+        let mut widget = SortableListWidget { sortable_list: SortableList::from(vec![1, 2, 3]) };
+        // The item was removed
+        assert!(dispatch(widget.clone(), "s_3").await.is_ignored());
+        // There is no selected item to move
+        assert!(dispatch(widget.clone(), "s_d").await.is_ignored());
+
+        widget.sortable_list.select(Some(2));
+        // The selected item is already the last one
+        assert!(dispatch(widget.clone(), "s_d").await.is_ignored());
+        // Forged data isn't parsed
+        assert!(!dispatch(widget.clone(), "s_uu").await.handled);
+        assert!(!dispatch(widget.clone(), "s_-1").await.handled);
+
+        let dispatched = dispatch(widget, "s_t").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().sortable_list.items(), [3, 1, 2]);
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(SortableList::from(vec![1, 2]).size(), Size::new(3, 3));
+        assert_eq!(SortableList::from(vec![0; 300]).size(), Size::new(u8::MAX, 3));
+    }
+}
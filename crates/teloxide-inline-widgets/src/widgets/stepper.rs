@@ -0,0 +1,463 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Numeric stepper widget
+///
+/// The value is changed by the `step` (or by the optional `big_step`) within
+/// the `min..=max` bounds. The optional preset buttons set the value directly
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stepper<N> {
+    value: N,
+    min: N,
+    max: N,
+    /// Step of the `increase/decrease` buttons, it's positive
+    step: N,
+    /// Step of the `big increase/decrease` buttons, they aren't displayed if
+    /// the step isn't set
+    big_step: Option<N>,
+    /// Values of the buttons beneath the stepper row
+    presets: Vec<N>,
+}
+
+/// Action performed by a [`Stepper`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepperAction {
+    Increase,
+    Decrease,
+    BigIncrease,
+    BigDecrease,
+    /// Sets the value of the preset with the specified index
+    Preset(usize),
+}
+
+impl StepperAction {
+    /// Returns the part of the callback query data following the prefix
+    fn data(self) -> String {
+        match self {
+            Self::Increase => "+".to_owned(),
+            Self::Decrease => "-".to_owned(),
+            Self::BigIncrease => "++".to_owned(),
+            Self::BigDecrease => "--".to_owned(),
+            Self::Preset(i) => format!("={i}"),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        let action = match data {
+            "+" => Self::Increase,
+            "-" => Self::Decrease,
+            "++" => Self::BigIncrease,
+            "--" => Self::BigDecrease,
+            _ => Self::Preset(data.strip_prefix('=')?.parse().ok()?),
+        };
+
+        Some(action)
+    }
+}
+
+impl<N> Stepper<N>
+where
+    N: Copy + PartialOrd + Add<Output = N> + Sub<Output = N>,
+{
+    /// Creates new [`Stepper`] instance with the `value` within the
+    /// `min..=max` bounds, which is changed by the `step`
+    ///
+    /// Panics if the `min` is greater than the `max`, the `value` is out of
+    /// bounds or the `step` isn't positive
+    pub fn new(value: N, min: N, max: N, step: N) -> Self {
+        assert!(min <= max);
+
+        let mut stepper = Self { value: min, min, max, step, big_step: None, presets: vec![] };
+        assert!(step > stepper.zero());
+        stepper.set_value(value);
+        stepper
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> N {
+        self.value
+    }
+
+    /// Sets the current value
+    ///
+    /// Panics if the value is out of bounds
+    pub fn set_value(&mut self, value: N) {
+        assert!(self.is_within_bounds(value));
+
+        self.value = value;
+    }
+
+    /// Returns the lower bound of the value
+    pub fn min(&self) -> N {
+        self.min
+    }
+
+    /// Returns the upper bound of the value
+    pub fn max(&self) -> N {
+        self.max
+    }
+
+    /// Returns the step of the `increase/decrease` buttons
+    pub fn step(&self) -> N {
+        self.step
+    }
+
+    /// Returns the step of the `big increase/decrease` buttons
+    pub fn big_step(&self) -> Option<N> {
+        self.big_step
+    }
+
+    /// Sets the step of the `big increase/decrease` buttons, they are hidden
+    /// if `None` is passed
+    ///
+    /// Panics if the step isn't positive
+    pub fn set_big_step(&mut self, big_step: Option<N>) {
+        if let Some(big_step) = big_step {
+            assert!(big_step > self.zero());
+        }
+
+        self.big_step = big_step;
+    }
+
+    /// Returns the values of the preset buttons
+    pub fn presets(&self) -> &[N] {
+        &self.presets
+    }
+
+    /// Sets the values of the preset buttons
+    ///
+    /// Panics if any of the values is out of bounds
+    pub fn set_presets(&mut self, presets: impl IntoIterator<Item = N>) {
+        let presets = Vec::from_iter(presets);
+        assert!(presets.iter().all(|&value| self.is_within_bounds(value)));
+
+        self.presets = presets;
+    }
+
+    fn is_within_bounds(&self, value: N) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Increases the value by the `step`, stops at the `max`
+    pub fn increase(&mut self) {
+        self.value = self.increased_by(self.step);
+    }
+
+    /// Decreases the value by the `step`, stops at the `min`
+    pub fn decrease(&mut self) {
+        self.value = self.decreased_by(self.step);
+    }
+
+    /// Returns the zero of the `N` type, there is no such constant for the
+    /// generic numbers
+    #[allow(clippy::eq_op)]
+    fn zero(&self) -> N {
+        self.min - self.min
+    }
+
+    // The operands are chosen by their signs, so neither of the expressions
+    // overflows for the signed and unsigned integers if the `step` is positive
+    fn increased_by(&self, step: N) -> N {
+        let zero = self.zero();
+        if self.value >= zero {
+            // `max - value` is within `0..=max`
+            if self.max - self.value <= step {
+                self.max
+            } else {
+                self.value + step
+            }
+        } else {
+            // `value + step` is within `value..step`
+            let value = self.value + step;
+            if value >= self.max {
+                self.max
+            } else {
+                value
+            }
+        }
+    }
+
+    fn decreased_by(&self, step: N) -> N {
+        let zero = self.zero();
+        if self.min >= zero {
+            // `value - min` is within `0..=value`
+            if self.value - self.min <= step {
+                self.min
+            } else {
+                self.value - step
+            }
+        } else {
+            // `min + step` is within `min..step`
+            if self.value <= self.min + step {
+                self.min
+            } else {
+                self.value - step
+            }
+        }
+    }
+
+    /// Returns the value after the `action` is applied, `None` is returned if
+    /// the action is unavailable
+    fn value_after(&self, action: StepperAction) -> Option<N> {
+        match action {
+            StepperAction::Increase => Some(self.increased_by(self.step)),
+            StepperAction::Decrease => Some(self.decreased_by(self.step)),
+            StepperAction::BigIncrease => Some(self.increased_by(self.big_step?)),
+            StepperAction::BigDecrease => Some(self.decreased_by(self.big_step?)),
+            StepperAction::Preset(i) => self.presets.get(i).copied(),
+        }
+    }
+
+    /// Returns `true` if the `action` is available and changes the value, so
+    /// the stale and forged actions aren't applied
+    fn can_apply(&self, action: StepperAction) -> bool {
+        self.value_after(action).is_some_and(|value| value != self.value)
+    }
+
+    /// [`dptree`]-schema for the [`Stepper`] widget
+    ///
+    /// The `on_change` handler is invoked after the value is changed and the
+    /// widget is redrawn. It receives the updated `W` widget and the new value
+    /// of the `N` type
+    pub fn schema<W>(
+        parameters: &'static StepperSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        N: 'static + Send + Sync,
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                StepperAction::from_data(cq.data?.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|mut widget: W, action: StepperAction| {
+                    !widget.get_widget().can_apply(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the button which doesn't change the value");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .filter_map(|mut widget: W, action: StepperAction| {
+                widget.get_widget().value_after(action)
+            })
+            .map(|mut widget: W, value: N| {
+                widget.get_widget().set_value(value);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .branch(on_change)
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Stepper`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// The buttons which can't change the value are displayed as empty cells
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &StepperSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup
+    where
+        N: Display,
+    {
+        let stepper_style = &styles.stepper_style;
+        let button = |text: &str, action: StepperAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+        let noop_button = |text: &str| InlineKeyboardButton::callback(text, parameters.noop_data);
+        let step_button = |text: &str, action: StepperAction, is_available: bool| {
+            if is_available {
+                button(text, action)
+            } else {
+                noop_button(&styles.common_style.empty_cell_icon)
+            }
+        };
+        let (is_min, is_max) = (self.value <= self.min, self.value >= self.max);
+
+        let mut stepper_row = vec![
+            step_button(&stepper_style.decrease_icon, StepperAction::Decrease, !is_min),
+            noop_button(&self.value.to_string()),
+            step_button(&stepper_style.increase_icon, StepperAction::Increase, !is_max),
+        ];
+        if self.big_step.is_some() {
+            stepper_row.insert(
+                0,
+                step_button(&stepper_style.big_decrease_icon, StepperAction::BigDecrease, !is_min),
+            );
+            stepper_row.push(step_button(
+                &stepper_style.big_increase_icon,
+                StepperAction::BigIncrease,
+                !is_max,
+            ));
+        }
+
+        let columns = stepper_row.len();
+        let mut keyboard = vec![stepper_row];
+        let presets = self
+            .presets
+            .iter()
+            .enumerate()
+            .map(|(i, value)| button(&value.to_string(), StepperAction::Preset(i)))
+            .collect::<Vec<_>>();
+        keyboard.extend(presets.chunks(columns).map(|row| row.to_vec()));
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl<N> GetSize for Stepper<N> {
+    fn size(&self) -> Size {
+        let columns: u8 = if self.big_step.is_some() { 5 } else { 3 };
+        let preset_rows = self.presets.len().div_ceil(columns.into());
+        Size { rows: (preset_rows + 1).try_into().unwrap_or(u8::MAX), columns }
+    }
+}
+
+pub struct StepperSchemaParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<StepperWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct StepperWidget {
+        #[stepper(prefix = "s_")]
+        stepper: Stepper<i32>,
+    }
+
+    #[test]
+    fn bounds() {
+        let mut stepper = Stepper::new(1u8, 0, 255, 1);
+        stepper.decrease();
+        stepper.decrease();
+        assert_eq!(stepper.value(), 0);
+
+        stepper.set_big_step(Some(100));
+        stepper.set_value(200);
+        assert_eq!(stepper.value_after(StepperAction::BigIncrease), Some(255));
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(100));
+        stepper.increase();
+        assert_eq!(stepper.value(), 201);
+    }
+
+    #[test]
+    fn signed_bounds() {
+        let mut stepper = Stepper::new(-100i8, -100, 100, 1);
+        stepper.set_big_step(Some(100));
+        assert_eq!(stepper.value_after(StepperAction::BigIncrease), Some(0));
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(-100));
+        stepper.set_value(100);
+        assert_eq!(stepper.value_after(StepperAction::BigIncrease), Some(100));
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(0));
+        stepper.set_value(-50);
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(-100));
+
+        let mut stepper = Stepper::new(i8::MIN, i8::MIN, i8::MAX, 1);
+        stepper.set_big_step(Some(i8::MAX));
+        assert_eq!(stepper.value_after(StepperAction::BigIncrease), Some(-1));
+        stepper.set_value(i8::MAX);
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(0));
+        stepper.set_value(0);
+        assert_eq!(stepper.value_after(StepperAction::BigDecrease), Some(-127));
+        assert_eq!(stepper.value_after(StepperAction::BigIncrease), Some(i8::MAX));
+
+        let stepper = Stepper::new(3u8, 2, 10, 100);
+        assert_eq!(stepper.value_after(StepperAction::Increase), Some(10));
+        assert_eq!(stepper.value_after(StepperAction::Decrease), Some(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn value_out_of_bounds() {
+        Stepper::new(11, 1, 10, 1);
+    }
+
+    #[test]
+    fn presets() {
+        let mut stepper = Stepper::new(1.5, 0.5, 5.0, 0.5);
+        stepper.set_presets([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(stepper.value_after(StepperAction::Preset(3)), Some(4.0));
+        assert_eq!(stepper.value_after(StepperAction::Preset(4)), None);
+        assert_eq!(stepper.size(), Size::new(3, 3));
+
+        let parameters = StepperSchemaParameters { prefix: "s_", noop_data: "noop" };
+        let keyboard =
+            stepper.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard[0].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["➖", "1.5", "➕"]);
+        assert_eq!(keyboard[2].len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_positive_step() {
+        Stepper::new(0, -10, 10, -1);
+    }
+
+    #[test]
+    fn size() {
+        let mut stepper = Stepper::new(0u16, 0, 1000, 1);
+        stepper.set_presets(0..1000);
+        assert_eq!(stepper.size(), Size::new(u8::MAX, 3));
+    }
+
+    #[tokio::test]
+    async fn stale_actions() {
+        let mut stepper = Stepper::new(10, 0, 10, 1);
+        stepper.set_presets([10, 7]);
+        let widget = StepperWidget { stepper };
+
+        // The value is the max, the big step buttons aren't displayed, the preset
+        // doesn't change the value and the preset was removed
+        for data in ["s_+", "s_++", "s_=0", "s_=2"] {
+            assert!(dispatch(widget.clone(), data).await.is_ignored());
+        }
+        assert!(!dispatch(widget.clone(), "s_=-1").await.handled);
+
+        let dispatched = dispatch(widget, "s_-").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().stepper.value(), 9);
+    }
+}
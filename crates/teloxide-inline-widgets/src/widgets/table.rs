@@ -0,0 +1,485 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+    widgets::pagination::{pages_count, paginated_size, pagination_row},
+};
+
+/// Maximum number of the [`Table`] columns, the inline keyboard row can't
+/// contain more buttons
+const MAX_COLUMNS: usize = 8;
+
+/// Sort order of the [`Table`] column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TableSortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Position of the clicked [`Table`] cell. Used as a unique type in the
+/// [`dptree`]-handler schema
+///
+/// The `row` is the index of the row in the [`Table::rows`], regardless of the
+/// sorting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableCell {
+    pub row: usize,
+    pub column: usize,
+}
+
+/// Table widget
+///
+/// Displays the header row with the rows of the cells beneath. The click on the
+/// header sorts the rows by the column, the numeric cells are compared as
+/// numbers. If the rows don't fit within the `page_rows`, the table is split
+/// into pages with the navigation row beneath the rows
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    /// Maximum number of the rows displayed on a page
+    page_rows: u8,
+    /// Currently displayed page, 0-based
+    page: usize,
+    /// The column the rows are sorted by
+    sort: Option<(usize, TableSortOrder)>,
+}
+
+/// Action performed by a [`Table`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableAction {
+    /// Sorts the rows by the column with the specified index
+    Sort(usize),
+    Page(usize),
+    Click(TableCell),
+}
+
+impl TableAction {
+    /// Returns the part of the callback query data following the prefix, the
+    /// pages have their own prefix
+    fn data(self) -> String {
+        match self {
+            Self::Sort(column) => format!("h{column}"),
+            Self::Page(page) => page.to_string(),
+            Self::Click(TableCell { row, column }) => format!("{row}_{column}"),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        if let Some(column) = data.strip_prefix('h') {
+            return Some(Self::Sort(column.parse().ok()?));
+        }
+        let (row, column) = data.split_once('_')?;
+        Some(Self::Click(TableCell { row: row.parse().ok()?, column: column.parse().ok()? }))
+    }
+}
+
+impl Table {
+    /// Creates new [`Table`] instance with the `headers` and the `rows` of the
+    /// cells, displaying up to `page_rows` rows on a page
+    ///
+    /// Panics if there are more than 8 headers or any of the rows doesn't match
+    /// the number of the headers
+    pub fn new(
+        headers: impl IntoIterator<Item = impl Into<String>>,
+        rows: impl IntoIterator<Item = Vec<String>>,
+        page_rows: u8,
+    ) -> Self {
+        let headers = Vec::from_iter(headers.into_iter().map(Into::into));
+        assert!(
+            headers.len() <= MAX_COLUMNS,
+            "Table can't have more than {MAX_COLUMNS} columns, got {}",
+            headers.len()
+        );
+
+        let mut table = Self { headers, rows: vec![], page_rows, page: 0, sort: None };
+        table.set_rows(rows);
+        table
+    }
+
+    /// Returns the headers of the columns
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Returns the rows of the cells in the original order
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Replaces the rows of the cells, the sorting is kept
+    ///
+    /// Panics if any of the rows doesn't match the number of the headers
+    pub fn set_rows(&mut self, rows: impl IntoIterator<Item = Vec<String>>) {
+        let rows = Vec::from_iter(rows);
+        assert!(rows.iter().all(|row| row.len() == self.headers.len()));
+
+        self.rows = rows;
+        self.page = self.page.min(self.pages_count() - 1);
+    }
+
+    /// Returns the column the rows are sorted by and the sort order
+    pub fn sort(&self) -> Option<(usize, TableSortOrder)> {
+        self.sort
+    }
+
+    /// Sorts the rows by the column, `None` restores the original order
+    ///
+    /// Panics if the column is out of bounds
+    pub fn set_sort(&mut self, sort: Option<(usize, TableSortOrder)>) {
+        if let Some((column, _)) = sort {
+            assert!(column < self.headers.len());
+        }
+
+        self.sort = sort;
+        self.page = 0;
+    }
+
+    /// Returns the index of the currently displayed page
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages required to display all the rows
+    pub fn pages_count(&self) -> usize {
+        pages_count(self.rows.len(), Size::new(self.page_rows, 1))
+    }
+
+    /// Sets the currently displayed page by index
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_page(&mut self, page: usize) {
+        assert!(page < self.pages_count());
+
+        self.page = page;
+    }
+
+    /// Returns the indices of the rows in the displayed order
+    pub fn sorted_row_indices(&self) -> Vec<usize> {
+        let mut indices = (0..self.rows.len()).collect::<Vec<_>>();
+        if let Some((column, order)) = self.sort {
+            indices.sort_by(|&a, &b| {
+                let ordering = compare_cells(&self.rows[a][column], &self.rows[b][column]);
+                match order {
+                    TableSortOrder::Ascending => ordering,
+                    TableSortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+        indices
+    }
+
+    /// Checks whether the `action` can be performed, the stale buttons of the
+    /// previous keyboards can be clicked as well
+    fn can_apply(&self, action: TableAction) -> bool {
+        match action {
+            TableAction::Sort(column) => column < self.headers.len(),
+            TableAction::Page(page) => page < self.pages_count(),
+            TableAction::Click(TableCell { row, column }) => {
+                row < self.rows.len() && column < self.headers.len()
+            }
+        }
+    }
+
+    /// Sorts the rows by the `column` in the ascending order, or reverses the
+    /// order if they are already sorted by it
+    fn sort_by(&mut self, column: usize) {
+        let order = match self.sort {
+            Some((sort_column, TableSortOrder::Ascending)) if sort_column == column => {
+                TableSortOrder::Descending
+            }
+            _ => TableSortOrder::Ascending,
+        };
+        self.set_sort(Some((column, order)));
+    }
+
+    /// [`dptree`]-schema for the [`Table`] widget
+    ///
+    /// The `on_click` handler is invoked when the cell is clicked. It receives
+    /// the [`TableCell`] position and is supposed to answer the callback query,
+    /// like the [`Button`](crate::Button) handler. The cells aren't clickable
+    /// if the handler isn't set
+    pub fn schema<W>(
+        parameters: &'static TableSchemaParameters,
+        on_click: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_click = on_click.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                let data = cq.data?;
+                // The page prefix is checked first, since it may start with the prefix
+                if let Some(page) = data.strip_prefix(parameters.page_prefix) {
+                    return Some(TableAction::Page(page.parse().ok()?));
+                }
+                TableAction::from_data(data.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|mut widget: W, action: TableAction| {
+                    !widget.get_widget().can_apply(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the cell which doesn't exist anymore");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter_map(|action: TableAction| match action {
+                    TableAction::Click(cell) => Some(cell),
+                    _ => None,
+                })
+                .branch(on_click)
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, action: TableAction| {
+                let table: &mut Self = widget.get_widget();
+                match action {
+                    TableAction::Sort(column) => table.sort_by(column),
+                    TableAction::Page(page) => table.set_page(page),
+                    TableAction::Click(_) => {}
+                }
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Table`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// Only the rows of the current page are displayed
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &TableSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let table_style = &styles.table_style;
+        let button = |text: String, action: TableAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+        let noop_button = |text: String| InlineKeyboardButton::callback(text, parameters.noop_data);
+
+        let header_row = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| {
+                let text = match self.sort {
+                    Some((sort_column, order)) if sort_column == column => {
+                        let icon = match order {
+                            TableSortOrder::Ascending => &table_style.ascending_icon,
+                            TableSortOrder::Descending => &table_style.descending_icon,
+                        };
+                        format!("{header} {icon}")
+                    }
+                    _ => header.clone(),
+                };
+                button(text, TableAction::Sort(column))
+            })
+            .collect();
+        let mut keyboard = vec![header_row];
+
+        let page_rows = self.page_rows as usize;
+        let pages_count = self.pages_count();
+        let row_indices = self.sorted_row_indices();
+        let page_row_indices = row_indices.iter().skip(self.page * page_rows).take(page_rows);
+        keyboard.extend(page_row_indices.map(|&row| {
+            self.rows[row]
+                .iter()
+                .enumerate()
+                .map(|(column, cell)| {
+                    if parameters.is_clickable {
+                        button(cell.clone(), TableAction::Click(TableCell { row, column }))
+                    } else {
+                        noop_button(cell.clone())
+                    }
+                })
+                .collect()
+        }));
+
+        if pages_count > 1 {
+            // The last page is padded with the empty cells to keep the size
+            let empty_row = vec![
+                noop_button(styles.common_style.empty_cell_icon.to_string());
+                self.headers.len()
+            ];
+            keyboard.resize(page_rows + 1, empty_row);
+            keyboard.push(pagination_row(
+                self.page,
+                pages_count,
+                parameters.page_prefix,
+                parameters.noop_data,
+                styles,
+            ));
+        }
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+/// Compares the cells as numbers if both of them are numeric
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+impl GetSize for Table {
+    fn size(&self) -> Size {
+        let rows = self.rows.len().min(self.page_rows as usize).try_into().unwrap_or(u8::MAX);
+        let columns = self.headers.len().try_into().unwrap_or(u8::MAX);
+        let Size { rows, columns } = paginated_size(Size::new(rows, columns), self.pages_count());
+        // The header row is displayed above the rows
+        Size { rows: rows.saturating_add(1), columns }
+    }
+}
+
+pub struct TableSchemaParameters {
+    /// CallbackQuery data prefix of the headers and the cells
+    pub prefix: &'static str,
+    /// CallbackQuery data prefix to be sent with the index of the selected
+    /// page
+    pub page_prefix: &'static str,
+    pub noop_data: &'static str,
+    /// Whether the cells send their positions when clicked
+    pub is_clickable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<TableWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct TableWidget {
+        #[table(prefix = "t_", on_click = click)]
+        table: Table,
+    }
+
+    async fn click(
+        bot: Bot,
+        TableCell { row, column }: TableCell,
+        (chat_id, _, cq_id): (ChatId, MessageId, String),
+    ) -> Result<(), Error> {
+        bot.answer_callback_query(cq_id).await?;
+        bot.send_message(chat_id, format!("{row}_{column}")).await?;
+
+        Ok(())
+    }
+
+    fn table() -> Table {
+        let rows = [["#1", "10.5"], ["#2", "9"], ["#3", "100"]];
+        Table::new(["Order", "Sum"], rows.map(|row| row.map(ToOwned::to_owned).to_vec()), 2)
+    }
+
+    #[test]
+    fn sorting() {
+        let mut table = table();
+        table.set_page(1);
+        table.sort_by(1);
+        assert_eq!(table.page(), 0);
+        assert_eq!(table.sorted_row_indices(), [1, 0, 2]);
+
+        table.sort_by(1);
+        assert_eq!(table.sort(), Some((1, TableSortOrder::Descending)));
+        assert_eq!(table.sorted_row_indices(), [2, 0, 1]);
+
+        table.sort_by(0);
+        assert_eq!(table.sorted_row_indices(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn markup() {
+        let mut table = table();
+        table.set_sort(Some((1, TableSortOrder::Descending)));
+        table.set_page(1);
+        assert_eq!(table.size(), Size::new(4, 3));
+
+        let parameters = TableSchemaParameters {
+            prefix: "t_",
+            page_prefix: "t_p_",
+            noop_data: "noop",
+            is_clickable: true,
+        };
+        let keyboard =
+            table.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard
+            .iter()
+            .map(|row| row.iter().map(|button| button.text.as_str()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(texts[0], ["Order", "Sum 🔽"]);
+        assert_eq!(texts[1], ["#2", "9"]);
+        assert_eq!(texts[2], ["✖️", "✖️"]);
+        assert_eq!(texts[3].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn stale_actions() {
+        let widget = TableWidget { table: table() };
+        // The column doesn't exist
+        assert!(dispatch(widget.clone(), "t_h2").await.is_ignored());
+        // The rows were removed
+        assert!(dispatch(widget.clone(), "t_p_2").await.is_ignored());
+        assert!(dispatch(widget.clone(), "t_3_0").await.is_ignored());
+        // Forged data isn't parsed
+        assert!(!dispatch(widget.clone(), "t_h").await.handled);
+        assert!(!dispatch(widget.clone(), "t_1_").await.handled);
+
+        let dispatched = dispatch(widget, "t_2_1").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "SendMessage"]);
+        assert_eq!(dispatched.requests[1].body["text"], "2_1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_length_mismatch() {
+        Table::new(["A", "B"], [vec!["1".to_owned()]], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_columns() {
+        Table::new(["A"; 9], [], 1);
+    }
+
+    #[test]
+    fn size() {
+        let table = Table::new(["A"; 8], vec![vec!["1".to_owned(); 8]; 300], u8::MAX);
+        assert_eq!(table.size(), Size::new(u8::MAX, 8));
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Tab bar widget
+///
+/// Being a field of the `user-defined` widget it turns the other fields into
+/// the tabs: only the field of the active tab is displayed beneath the tab bar
+/// and receives the callback queries. The tabs correspond to the fields in the
+/// declaration order
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tabs {
+    labels: Vec<String>,
+    active_tab: usize,
+}
+
+/// Index of a [`Tabs`] tab. Used as a unique type in the [`dptree`]-handler
+/// schema
+#[derive(Debug, Clone)]
+struct TabIndex(usize);
+
+impl Tabs {
+    /// Creates new [`Tabs`] instance from the labels of the tabs, the first
+    /// tab is active
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let labels = labels.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        if labels.is_empty() {
+            log::warn!("Tabs are empty");
+        }
+
+        Self { labels, active_tab: 0 }
+    }
+
+    /// Returns the labels of the tabs
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the index of the active tab
+    pub fn active_tab(&self) -> usize {
+        self.active_tab
+    }
+
+    /// Sets the active tab by index
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_active_tab(&mut self, i: usize) {
+        assert!(i < self.labels.len());
+
+        self.active_tab = i;
+    }
+
+    /// [`dptree`]-schema for the [`Tabs`] widget
+    pub fn schema<W>(parameters: &'static TabsSchemaParameters) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |mut widget: W, cq: CallbackQuery| {
+                let i = cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?;
+                (i < widget.get_widget().labels.len()).then_some(TabIndex(i))
+            })
+            .branch(
+                dptree::filter(|mut widget: W, TabIndex(i): TabIndex| {
+                    widget.get_widget().active_tab == i
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, TabIndex(i): TabIndex| {
+                widget.get_widget().set_active_tab(i);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Tabs`] widget with
+    /// specified callback query `prefix`
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &TabsSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        if self.labels.len() != parameters.tabs {
+            log::warn!(
+                "Tabs have {} labels, but the widget has {} tabs",
+                self.labels.len(),
+                parameters.tabs
+            );
+        }
+
+        InlineKeyboardMarkup::new(vec![self
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let text = if i == self.active_tab {
+                    styles.tabs_style.active_tab_format.replace("{label}", label)
+                } else {
+                    label.clone()
+                };
+                InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, i))
+            })
+            .collect::<Vec<_>>()])
+    }
+}
+
+impl GetSize for Tabs {
+    fn size(&self) -> Size {
+        Size { rows: 1, columns: self.labels.len().try_into().unwrap_or(u8::MAX) }
+    }
+}
+
+pub struct TabsSchemaParameters {
+    /// CallbackQuery data prefix to be sent with the index of the clicked tab
+    pub prefix: &'static str,
+    /// Number of the tabs
+    pub tabs: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*, CheckboxList, RadioList};
+
+    type Dialogue = TestDialogue<TabsWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct TabsWidget {
+        #[tabs(prefix = "t_")]
+        tabs: Tabs,
+        #[radio_list(prefix = "a_")]
+        first: RadioList<u8>,
+        #[checkbox_list(prefix = "b_")]
+        second: CheckboxList<u8>,
+    }
+
+    #[test]
+    fn tabs() {
+        let mut tabs = Tabs::new(["Filters", "Sort"]);
+        tabs.set_active_tab(1);
+        assert_eq!(tabs.size(), Size::new(1, 2));
+
+        let parameters = TabsSchemaParameters { prefix: "t_", tabs: 2 };
+        let keyboard =
+            tabs.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard[0].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["Filters", "• Sort •"]);
+    }
+
+    #[tokio::test]
+    async fn hidden_tabs_callbacks() {
+        let widget = TabsWidget {
+            tabs: Tabs::new(["First", "Second"]),
+            first: RadioList::new([1, 2], None, Size::new(1, 2)),
+            second: CheckboxList::new([(false, 1), (false, 2)], Size::new(1, 2)),
+        };
+
+        let dispatched = dispatch(widget.clone(), "a_1").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().first.active_item(), Some(&2));
+
+        // The second tab is hidden
+        let dispatched = dispatch(widget, "b_1").await;
+        assert!(!dispatched.handled);
+        assert!(dispatched.requests.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn active_tab_out_of_bounds() {
+        Tabs::new(["Filters", "Sort"]).set_active_tab(2);
+    }
+}
@@ -0,0 +1,331 @@
+use chrono::{NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Time picker widget
+///
+/// The hours and the minutes are changed independently with the `up` and
+/// `down` buttons, the minutes are changed by the `minute_step`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TimePicker {
+    time: NaiveTime,
+    /// Step of the minute buttons, in minutes
+    minute_step: u32,
+    /// Whether the hours are displayed in the 12-hour format
+    twelve_hour: bool,
+}
+
+/// Action performed by a [`TimePicker`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimePickerAction {
+    IncreaseHour,
+    DecreaseHour,
+    IncreaseMinute,
+    DecreaseMinute,
+    ToggleMeridiem,
+    Confirm,
+}
+
+impl TimePickerAction {
+    /// Returns the part of the callback query data following the prefix
+    fn data(self) -> &'static str {
+        match self {
+            Self::IncreaseHour => "hu",
+            Self::DecreaseHour => "hd",
+            Self::IncreaseMinute => "mu",
+            Self::DecreaseMinute => "md",
+            Self::ToggleMeridiem => "ap",
+            Self::Confirm => "ok",
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        [
+            Self::IncreaseHour,
+            Self::DecreaseHour,
+            Self::IncreaseMinute,
+            Self::DecreaseMinute,
+            Self::ToggleMeridiem,
+            Self::Confirm,
+        ]
+        .into_iter()
+        .find(|action| action.data() == data)
+    }
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self::new(NaiveTime::default())
+    }
+}
+
+impl TimePicker {
+    /// Creates new [`TimePicker`] instance with the specified `time`, the
+    /// seconds are dropped
+    pub fn new(time: NaiveTime) -> Self {
+        let mut time_picker =
+            Self { time: NaiveTime::default(), minute_step: 1, twelve_hour: false };
+        time_picker.set_time(time);
+        time_picker
+    }
+
+    /// Returns the selected time
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// Sets the selected time, the seconds are dropped and the minutes are
+    /// rounded down to the `minute_step`
+    pub fn set_time(&mut self, time: NaiveTime) {
+        let minute = time.minute() - time.minute() % self.minute_step;
+        self.time = NaiveTime::from_hms_opt(time.hour(), minute, 0).unwrap();
+    }
+
+    /// Returns the step of the minute buttons
+    pub fn minute_step(&self) -> u32 {
+        self.minute_step
+    }
+
+    /// Sets the step of the minute buttons, the selected minutes are rounded
+    /// down to the step
+    ///
+    /// Panics if the step is not within `1..60`
+    pub fn set_minute_step(&mut self, minute_step: u32) {
+        assert!((1..60).contains(&minute_step));
+
+        self.minute_step = minute_step;
+        self.set_time(self.time);
+    }
+
+    /// Returns `true` if the hours are displayed in the 12-hour format
+    pub fn is_twelve_hour(&self) -> bool {
+        self.twelve_hour
+    }
+
+    /// Sets whether the hours are displayed in the 12-hour format
+    pub fn set_twelve_hour(&mut self, twelve_hour: bool) {
+        self.twelve_hour = twelve_hour;
+    }
+
+    /// Sets the hour and the minute, they are supposed to be valid
+    fn set_hm(&mut self, hour: u32, minute: u32) {
+        self.time = NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+    }
+
+    /// Increases the hour, wraps around midnight
+    pub fn increase_hour(&mut self) {
+        self.set_hm((self.time.hour() + 1) % 24, self.time.minute());
+    }
+
+    /// Decreases the hour, wraps around midnight
+    pub fn decrease_hour(&mut self) {
+        self.set_hm((self.time.hour() + 23) % 24, self.time.minute());
+    }
+
+    /// Increases the minute by the `minute_step`, doesn't change the hour
+    pub fn increase_minute(&mut self) {
+        let minute = self.time.minute() + self.minute_step;
+        self.set_hm(self.time.hour(), if minute < 60 { minute } else { 0 });
+    }
+
+    /// Decreases the minute by the `minute_step`, doesn't change the hour
+    pub fn decrease_minute(&mut self) {
+        let minute = match self.time.minute().checked_sub(self.minute_step) {
+            Some(minute) => minute,
+            // The last minute that is a multiple of the step
+            None => 59 - 59 % self.minute_step,
+        };
+        self.set_hm(self.time.hour(), minute);
+    }
+
+    /// Switches between `AM` and `PM`
+    pub fn toggle_meridiem(&mut self) {
+        self.set_hm((self.time.hour() + 12) % 24, self.time.minute());
+    }
+
+    fn apply(&mut self, action: TimePickerAction) {
+        match action {
+            TimePickerAction::IncreaseHour => self.increase_hour(),
+            TimePickerAction::DecreaseHour => self.decrease_hour(),
+            TimePickerAction::IncreaseMinute => self.increase_minute(),
+            TimePickerAction::DecreaseMinute => self.decrease_minute(),
+            TimePickerAction::ToggleMeridiem => self.toggle_meridiem(),
+            TimePickerAction::Confirm => {}
+        }
+    }
+
+    /// [`dptree`]-schema for the [`TimePicker`] widget
+    ///
+    /// The `on_confirm` handler is invoked when the confirm button is clicked.
+    /// It receives the selected [`NaiveTime`] and is supposed to answer the
+    /// callback query, like the [`Button`](crate::Button) handler
+    pub fn schema<W>(
+        parameters: &'static TimePickerSchemaParameters,
+        on_confirm: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_confirm = on_confirm.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                TimePickerAction::from_data(cq.data?.strip_prefix(parameters.prefix)?)
+            })
+            .branch(
+                dptree::filter(|action: TimePickerAction| action == TimePickerAction::Confirm)
+                    .map(|mut widget: W| widget.get_widget().time())
+                    .branch(on_confirm)
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+
+                            Ok(())
+                        },
+                    ),
+            )
+            .map(|mut widget: W, action: TimePickerAction| {
+                widget.get_widget().apply(action);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`TimePicker`] widget with
+    /// specified callback query `prefix`
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &TimePickerSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let time_picker_style = &styles.time_picker_style;
+        let button = |text: &str, action: TimePickerAction| {
+            InlineKeyboardButton::callback(text, format!("{}{}", parameters.prefix, action.data()))
+        };
+        let noop_button = |text: &str| InlineKeyboardButton::callback(text, parameters.noop_data);
+        let empty_cell = || noop_button(&styles.common_style.empty_cell_icon);
+
+        let (is_pm, hour) = if self.twelve_hour {
+            let (is_pm, hour) = self.time.hour12();
+            (Some(is_pm), hour)
+        } else {
+            (None, self.time.hour())
+        };
+
+        let mut keyboard = vec![
+            vec![
+                button(&time_picker_style.increase_icon, TimePickerAction::IncreaseHour),
+                empty_cell(),
+                button(&time_picker_style.increase_icon, TimePickerAction::IncreaseMinute),
+            ],
+            vec![
+                noop_button(&format!("{hour:02}")),
+                noop_button(&time_picker_style.separator),
+                noop_button(&format!("{:02}", self.time.minute())),
+            ],
+            vec![
+                button(&time_picker_style.decrease_icon, TimePickerAction::DecreaseHour),
+                empty_cell(),
+                button(&time_picker_style.decrease_icon, TimePickerAction::DecreaseMinute),
+            ],
+        ];
+        if let Some(is_pm) = is_pm {
+            let meridiem =
+                if is_pm { &time_picker_style.pm_text } else { &time_picker_style.am_text };
+
+            keyboard[0].push(empty_cell());
+            keyboard[1].push(button(meridiem, TimePickerAction::ToggleMeridiem));
+            keyboard[2].push(empty_cell());
+        }
+        keyboard.push(vec![button(&time_picker_style.confirm_text, TimePickerAction::Confirm)]);
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+impl GetSize for TimePicker {
+    fn size(&self) -> Size {
+        Size { rows: 4, columns: if self.twelve_hour { 4 } else { 3 } }
+    }
+}
+
+pub struct TimePickerSchemaParameters {
+    /// CallbackQuery data prefix of the widget buttons
+    pub prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn hours() {
+        let mut time_picker = TimePicker::new(time(23, 30));
+        time_picker.increase_hour();
+        assert_eq!(time_picker.time(), time(0, 30));
+        time_picker.decrease_hour();
+        assert_eq!(time_picker.time(), time(23, 30));
+        time_picker.toggle_meridiem();
+        assert_eq!(time_picker.time(), time(11, 30));
+    }
+
+    #[test]
+    fn minutes() {
+        let mut time_picker = TimePicker::new(time(10, 59));
+        time_picker.set_minute_step(15);
+        assert_eq!(time_picker.time(), time(10, 45));
+
+        time_picker.increase_minute();
+        assert_eq!(time_picker.time(), time(10, 0));
+        time_picker.decrease_minute();
+        assert_eq!(time_picker.time(), time(10, 45));
+
+        time_picker.set_minute_step(7);
+        time_picker.set_time(time(10, 3));
+        time_picker.decrease_minute();
+        assert_eq!(time_picker.time(), time(10, 56));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_minute_step() {
+        TimePicker::default().set_minute_step(0);
+    }
+
+    #[test]
+    fn twelve_hour() {
+        let mut time_picker = TimePicker::new(time(0, 5));
+        time_picker.set_twelve_hour(true);
+        assert_eq!(time_picker.size(), Size::new(4, 4));
+
+        let parameters = TimePickerSchemaParameters { prefix: "t_", noop_data: "noop" };
+        let keyboard = time_picker
+            .inline_keyboard_markup(&parameters, &WidgetStyles::default())
+            .inline_keyboard;
+        let texts = keyboard[1].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["12", ":", "05", "AM"]);
+    }
+}
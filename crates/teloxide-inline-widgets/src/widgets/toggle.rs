@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    types::{CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Toggle switch widget
+///
+/// Single button which switches the `on/off` state when clicked. Unlike the
+/// other widgets, several toggles can be placed within the same widget
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Toggle {
+    /// Text that is displayed within a button after the state icon
+    pub label: String,
+    is_on: bool,
+}
+
+impl Toggle {
+    /// Creates new [`Toggle`] instance with provided label and state
+    pub fn new(label: &str, is_on: bool) -> Self {
+        Self { label: label.to_owned(), is_on }
+    }
+
+    /// Returns `true` if the toggle is on
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    /// Sets the state of the toggle
+    pub fn set_on(&mut self, is_on: bool) {
+        self.is_on = is_on;
+    }
+
+    /// Switches the state of the toggle
+    pub fn toggle(&mut self) {
+        self.is_on = !self.is_on;
+    }
+
+    /// [`dptree`]-schema for the [`Toggle`] widget
+    ///
+    /// The toggle is retrieved from the `C` widget, which contains it, by the
+    /// `toggle` accessor, so the toggles are told apart by the fields rather
+    /// than by the type.
+    ///
+    /// The `on_change` handler is invoked after the state is switched and the
+    /// widget is redrawn. It receives the updated `W` widget and the new
+    /// state as `bool`
+    pub fn schema<W, C>(
+        parameters: &'static ToggleSchemaParameters,
+        toggle: fn(&mut C) -> &mut Self,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        C: 'static,
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<C>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::filter(move |cq: CallbackQuery| cq.data.as_deref() == Some(parameters.data))
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .map(move |mut widget: W| {
+                toggle(widget.get_widget()).toggle();
+                widget
+            })
+            .chain(update_widget::<W>())
+            .map(move |mut widget: W| toggle(widget.get_widget()).is_on())
+            .branch(on_change)
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Toggle`] widget with
+    /// specified callback query `data`
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &ToggleSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        let icon =
+            if self.is_on { &styles.toggle_style.on_icon } else { &styles.toggle_style.off_icon };
+
+        InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
+            format!("{icon} {}", self.label),
+            parameters.data,
+        )]])
+    }
+}
+
+impl GetSize for Toggle {
+    fn size(&self) -> Size {
+        Size { rows: 1, columns: 1 }
+    }
+}
+
+pub struct ToggleSchemaParameters {
+    /// CallbackQuery data to be sent when the toggle is clicked
+    pub data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle() {
+        let mut toggle = Toggle::new("Notifications", false);
+        toggle.toggle();
+        assert!(toggle.is_on());
+
+        let parameters = ToggleSchemaParameters { data: "notifications" };
+        let keyboard =
+            toggle.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        assert_eq!(keyboard[0][0].text, "✅ Notifications");
+    }
+}
@@ -0,0 +1,402 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+    widgets::pagination::{pages_count, pagination_row},
+};
+
+/// Number of the item rows of the [`TransferList`] created from a [`Vec`]
+const DEFAULT_ROWS: u8 = 5;
+
+/// Transfer list widget
+///
+/// Displays the `available` and the `chosen` items in two columns, the
+/// clicked item is moved to the other column. The chosen items are kept in the
+/// order they were chosen. If the items don't fit within the `rows`, the
+/// columns are split into pages with the navigation row beneath the items
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TransferList<T> {
+    /// Number of the item rows of the [`TransferList`] widget (excluding the
+    /// header and the pagination rows)
+    pub rows: u8,
+    available: Vec<T>,
+    chosen: Vec<T>,
+    /// Currently displayed pages of the columns, 0-based
+    available_page: usize,
+    chosen_page: usize,
+    /// Number of the performed transfers, it's sent with the item buttons,
+    /// since the indices of the items are shifted after each transfer
+    #[serde(default)]
+    revision: u32,
+}
+
+/// Column of the [`TransferList`] widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferListColumn {
+    Available,
+    Chosen,
+}
+
+impl TransferListColumn {
+    /// Returns the part of the callback query data identifying the column
+    fn data(self) -> &'static str {
+        match self {
+            Self::Available => "a",
+            Self::Chosen => "c",
+        }
+    }
+
+    /// Splits the column identifier off the callback query data
+    fn split_data(data: &str) -> Option<(Self, &str)> {
+        [Self::Available, Self::Chosen]
+            .into_iter()
+            .find_map(|column| Some((column, data.strip_prefix(column.data())?)))
+    }
+}
+
+/// Action performed by a [`TransferList`] button. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferListAction {
+    /// Moves the item with the specified index to the other column, the
+    /// action is valid only for the specified revision of the widget
+    Transfer(TransferListColumn, u32, usize),
+    /// Displays the page with the specified index
+    Page(TransferListColumn, usize),
+}
+
+impl<T> TransferList<T> {
+    /// Creates new [`TransferList`] instance with all the `items` available
+    pub fn new(items: impl IntoIterator<Item = T>, rows: u8) -> Self {
+        Self {
+            rows,
+            available: items.into_iter().collect(),
+            chosen: vec![],
+            available_page: 0,
+            chosen_page: 0,
+            revision: 0,
+        }
+    }
+
+    /// Returns the items which aren't chosen
+    pub fn available(&self) -> &[T] {
+        &self.available
+    }
+
+    /// Returns the chosen items in the order they were chosen
+    pub fn chosen(&self) -> &[T] {
+        &self.chosen
+    }
+
+    /// Consumes the widget, returning the chosen items in the order they were
+    /// chosen
+    pub fn into_chosen(self) -> Vec<T> {
+        self.chosen
+    }
+
+    /// Moves the available item with the specified index to the end of the
+    /// chosen items
+    ///
+    /// Panics if the index is out of bounds
+    pub fn choose(&mut self, i: usize) {
+        let item = self.available.remove(i);
+        self.chosen.push(item);
+        self.transferred();
+    }
+
+    /// Moves the chosen item with the specified index back to the end of the
+    /// available items
+    ///
+    /// Panics if the index is out of bounds
+    pub fn unchoose(&mut self, i: usize) {
+        let item = self.chosen.remove(i);
+        self.available.push(item);
+        self.transferred();
+    }
+
+    /// Returns the indices of the currently displayed pages of the available
+    /// and the chosen items
+    pub fn pages(&self) -> (usize, usize) {
+        (self.available_page, self.chosen_page)
+    }
+
+    fn items(&self, column: TransferListColumn) -> &[T] {
+        match column {
+            TransferListColumn::Available => &self.available,
+            TransferListColumn::Chosen => &self.chosen,
+        }
+    }
+
+    fn page(&self, column: TransferListColumn) -> usize {
+        match column {
+            TransferListColumn::Available => self.available_page,
+            TransferListColumn::Chosen => self.chosen_page,
+        }
+    }
+
+    fn pages_count(&self, column: TransferListColumn) -> usize {
+        pages_count(self.items(column).len(), Size::new(self.rows, 1))
+    }
+
+    /// Invalidates the item buttons of the previous keyboards and keeps the
+    /// displayed pages within bounds after the items are moved
+    fn transferred(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+        self.available_page =
+            self.available_page.min(self.pages_count(TransferListColumn::Available) - 1);
+        self.chosen_page = self.chosen_page.min(self.pages_count(TransferListColumn::Chosen) - 1);
+    }
+
+    /// Checks whether the `action` can be performed, the stale buttons of the
+    /// previous keyboards can be clicked as well
+    fn can_apply(&self, action: TransferListAction) -> bool {
+        match action {
+            TransferListAction::Transfer(column, revision, i) => {
+                revision == self.revision && i < self.items(column).len()
+            }
+            TransferListAction::Page(column, page) => page < self.pages_count(column),
+        }
+    }
+
+    fn apply(&mut self, action: TransferListAction) {
+        match action {
+            TransferListAction::Transfer(TransferListColumn::Available, _, i) => self.choose(i),
+            TransferListAction::Transfer(TransferListColumn::Chosen, _, i) => self.unchoose(i),
+            TransferListAction::Page(TransferListColumn::Available, page) => {
+                self.available_page = page
+            }
+            TransferListAction::Page(TransferListColumn::Chosen, page) => self.chosen_page = page,
+        }
+    }
+
+    /// [`dptree`]-schema for the [`TransferList`] widget
+    ///
+    /// The `on_change` handler is invoked after the item is moved to the other
+    /// column and the widget is redrawn. It receives the updated `W` widget
+    pub fn schema<W>(
+        parameters: &'static TransferListSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                let data = cq.data?;
+                // The page prefix is checked first, since it may start with the prefix
+                if let Some(data) = data.strip_prefix(parameters.page_prefix) {
+                    let (column, page) = TransferListColumn::split_data(data)?;
+                    return Some(TransferListAction::Page(column, page.parse().ok()?));
+                }
+                let (column, data) =
+                    TransferListColumn::split_data(data.strip_prefix(parameters.prefix)?)?;
+                let (revision, i) = data.split_once('_')?;
+                Some(TransferListAction::Transfer(column, revision.parse().ok()?, i.parse().ok()?))
+            })
+            .branch(
+                dptree::filter(|mut widget: W, action: TransferListAction| {
+                    !widget.get_widget().can_apply(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the item which doesn't exist anymore");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .map(|mut widget: W, action: TransferListAction| {
+                widget.get_widget().apply(action);
+                widget
+            })
+            .chain(update_widget::<W>())
+            .branch(
+                dptree::filter(|action: TransferListAction| {
+                    matches!(action, TransferListAction::Transfer(..))
+                })
+                .branch(on_change),
+            )
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`TransferList`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// Only the items of the current pages are displayed
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &TransferListSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup
+    where
+        T: Display,
+    {
+        let transfer_list_style = &styles.transfer_list_style;
+        let noop_button = |text: &str| InlineKeyboardButton::callback(text, parameters.noop_data);
+        let rows = self.rows as usize;
+        let column_button = |column: TransferListColumn, row_i: usize| {
+            let i = self.page(column) * rows + row_i;
+            match self.items(column).get(i) {
+                Some(item) => InlineKeyboardButton::callback(
+                    item.to_string(),
+                    format!("{}{}{}_{i}", parameters.prefix, column.data(), self.revision),
+                ),
+                None => noop_button(&styles.common_style.empty_cell_icon),
+            }
+        };
+
+        let mut keyboard = vec![vec![
+            noop_button(&transfer_list_style.available_title),
+            noop_button(&transfer_list_style.chosen_title),
+        ]];
+        keyboard.extend((0..rows).map(|row_i| {
+            vec![
+                column_button(TransferListColumn::Available, row_i),
+                column_button(TransferListColumn::Chosen, row_i),
+            ]
+        }));
+        if self.is_paginated() {
+            let pagination_row = [TransferListColumn::Available, TransferListColumn::Chosen]
+                .into_iter()
+                .flat_map(|column| {
+                    pagination_row(
+                        self.page(column),
+                        self.pages_count(column),
+                        &format!("{}{}", parameters.page_prefix, column.data()),
+                        parameters.noop_data,
+                        styles,
+                    )
+                });
+            keyboard.push(pagination_row.collect());
+        }
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+
+    fn is_paginated(&self) -> bool {
+        self.pages_count(TransferListColumn::Available) > 1
+            || self.pages_count(TransferListColumn::Chosen) > 1
+    }
+}
+
+impl<T> From<Vec<T>> for TransferList<T> {
+    fn from(value: Vec<T>) -> Self {
+        TransferList::new(value, DEFAULT_ROWS)
+    }
+}
+
+impl<T> GetSize for TransferList<T> {
+    fn size(&self) -> Size {
+        // The pagination row consists of the navigation buttons of both columns
+        if self.is_paginated() {
+            Size { rows: self.rows.saturating_add(2), columns: 6 }
+        } else {
+            Size { rows: self.rows.saturating_add(1), columns: 2 }
+        }
+    }
+}
+
+pub struct TransferListSchemaParameters {
+    /// CallbackQuery data prefix to be sent with the column, the revision of
+    /// the widget and the index of the clicked item
+    pub prefix: &'static str,
+    /// CallbackQuery data prefix to be sent with the column and the index of
+    /// the selected page
+    pub page_prefix: &'static str,
+    pub noop_data: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::{types::InlineKeyboardButtonKind, Bot};
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<TransferListWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct TransferListWidget {
+        #[transfer_list(prefix = "t_")]
+        transfer_list: TransferList<u8>,
+    }
+
+    #[test]
+    fn transfer() {
+        let mut transfer_list = TransferList::new([1, 2, 3, 4, 5], 2);
+        assert_eq!(transfer_list.size(), Size::new(4, 6));
+
+        transfer_list.apply(TransferListAction::Page(TransferListColumn::Available, 2));
+        transfer_list.choose(4);
+        transfer_list.choose(0);
+        assert_eq!(transfer_list.pages(), (1, 0));
+        assert_eq!(transfer_list.chosen(), [5, 1]);
+
+        transfer_list.unchoose(0);
+        assert_eq!(transfer_list.available(), [2, 3, 4, 5]);
+        assert!(!transfer_list.can_apply(TransferListAction::Transfer(
+            TransferListColumn::Chosen,
+            3,
+            1
+        )));
+        assert_eq!(transfer_list.into_chosen(), [1]);
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(TransferList::from(vec![1; 300]).size(), Size::new(7, 6));
+        assert_eq!(TransferList::new([1], u8::MAX).size(), Size::new(u8::MAX, 2));
+    }
+
+    #[test]
+    fn markup() {
+        let mut transfer_list = TransferList::from(vec!["a", "b"]);
+        transfer_list.choose(1);
+        let parameters =
+            TransferListSchemaParameters { prefix: "t_", page_prefix: "t_p_", noop_data: "noop" };
+        let keyboard = transfer_list
+            .inline_keyboard_markup(&parameters, &WidgetStyles::default())
+            .inline_keyboard;
+        assert_eq!(keyboard.len(), 6);
+        assert_eq!(keyboard[1][0].text, "a");
+        assert_eq!(keyboard[1][1].text, "b");
+        assert_eq!(keyboard[2][1].text, "✖️");
+        assert_eq!(
+            keyboard[1][1].kind,
+            InlineKeyboardButtonKind::CallbackData("t_c1_0".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_transfers() {
+        let mut widget = TransferListWidget { transfer_list: TransferList::from(vec![1, 2, 3]) };
+        widget.transfer_list.choose(0);
+        // The button of the item `1` is clicked again, the item `2` is at its place now
+        assert!(dispatch(widget.clone(), "t_a0_0").await.is_ignored());
+        // Forged data isn't parsed
+        assert!(!dispatch(widget.clone(), "t_a1").await.handled);
+
+        let dispatched = dispatch(widget, "t_a1_0").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().transfer_list.chosen(), [1, 2]);
+    }
+}
@@ -0,0 +1,406 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Node of the [`TreeView`] tree
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum TreeNode<T> {
+    /// Expandable node with the nested nodes
+    Branch { label: String, children: Vec<TreeNode<T>> },
+    /// Selectable item
+    Leaf(T),
+}
+
+impl<T> TreeNode<T> {
+    /// Creates the [`TreeNode::Branch`] node
+    pub fn branch(label: impl Into<String>, children: impl IntoIterator<Item = Self>) -> Self {
+        Self::Branch { label: label.into(), children: children.into_iter().collect() }
+    }
+
+    /// Creates the [`TreeNode::Leaf`] node
+    pub fn leaf(item: T) -> Self {
+        Self::Leaf(item)
+    }
+}
+
+/// The way the clicked leaves are selected in the [`TreeView`] widget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TreeViewSelectionMode {
+    /// The clicked leaf becomes the selected one
+    #[default]
+    Single,
+    /// Each click toggles the selection of the leaf, like in the
+    /// [`CheckboxList`](crate::CheckboxList)
+    Multiple,
+}
+
+/// Tree view widget
+///
+/// Displays the visible nodes one per row, indented according to their depth.
+/// The click on the branch expands or collapses it in place, the click on the
+/// leaf selects it according to the [`TreeViewSelectionMode`]
+///
+/// The nodes are identified by their indices in the depth-first order of the
+/// whole tree
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TreeView<T> {
+    nodes: Vec<TreeNode<T>>,
+    /// Identifiers of the expanded branches
+    expanded: BTreeSet<usize>,
+    /// Identifiers of the selected leaves
+    selected: BTreeSet<usize>,
+    /// The way the clicked leaves are selected
+    selection_mode: TreeViewSelectionMode,
+}
+
+/// Identifier of the clicked [`TreeView`] node. Used as a unique type in the
+/// [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy)]
+struct TreeNodeId(usize);
+
+impl<T> TreeView<T> {
+    /// Creates new [`TreeView`] instance with the top-level `nodes`, all the
+    /// branches are collapsed
+    pub fn new(nodes: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            expanded: BTreeSet::new(),
+            selected: BTreeSet::new(),
+            selection_mode: TreeViewSelectionMode::Single,
+        }
+    }
+
+    /// Returns the top-level nodes
+    pub fn nodes(&self) -> &[TreeNode<T>] {
+        &self.nodes
+    }
+
+    /// Returns the way the clicked leaves are selected
+    pub fn selection_mode(&self) -> TreeViewSelectionMode {
+        self.selection_mode
+    }
+
+    /// Sets the way the clicked leaves are selected
+    pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) {
+        self.selection_mode = selection_mode;
+    }
+
+    /// Returns the selected items in the depth-first order
+    pub fn selected_items(&self) -> impl Iterator<Item = &T> {
+        self.flatten().into_iter().enumerate().filter_map(|(id, (_, node))| match node {
+            TreeNode::Leaf(item) if self.selected.contains(&id) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// Returns the first selected item, is useful in the
+    /// [`TreeViewSelectionMode::Single`]
+    pub fn selected_item(&self) -> Option<&T> {
+        self.selected_items().next()
+    }
+
+    /// Returns `true` if the node with the specified identifier is the
+    /// expanded branch
+    pub fn is_expanded(&self, id: usize) -> bool {
+        self.expanded.contains(&id)
+    }
+
+    /// Expands all the branches
+    pub fn expand_all(&mut self) {
+        self.expanded = self
+            .flatten()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, node))| matches!(node, TreeNode::Branch { .. }))
+            .map(|(id, _)| id)
+            .collect();
+    }
+
+    /// Collapses all the branches
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Returns all the nodes with their depths in the depth-first order, the
+    /// index of the node is its identifier
+    fn flatten(&self) -> Vec<(usize, &TreeNode<T>)> {
+        fn visit<'a, T>(
+            nodes: &'a [TreeNode<T>],
+            depth: usize,
+            flattened: &mut Vec<(usize, &'a TreeNode<T>)>,
+        ) {
+            for node in nodes {
+                flattened.push((depth, node));
+                if let TreeNode::Branch { children, .. } = node {
+                    visit(children, depth + 1, flattened);
+                }
+            }
+        }
+
+        let mut flattened = vec![];
+        visit(&self.nodes, 0, &mut flattened);
+        flattened
+    }
+
+    /// Returns the identifiers, depths and nodes which aren't hidden within
+    /// the collapsed branches
+    fn visible_nodes(&self) -> Vec<(usize, usize, &TreeNode<T>)> {
+        let mut visible_nodes = vec![];
+        // Depth of the collapsed branch whose descendants are skipped
+        let mut collapsed_depth = None;
+        for (id, (depth, node)) in self.flatten().into_iter().enumerate() {
+            if collapsed_depth.is_some_and(|collapsed_depth| depth > collapsed_depth) {
+                continue;
+            }
+            collapsed_depth = None;
+            visible_nodes.push((id, depth, node));
+            if matches!(node, TreeNode::Branch { .. }) && !self.is_expanded(id) {
+                collapsed_depth = Some(depth);
+            }
+        }
+        visible_nodes
+    }
+
+    /// Expands/collapses the branch or selects the leaf with the specified
+    /// identifier, `false` is returned if the node isn't visible
+    pub fn click(&mut self, id: usize) -> bool {
+        let is_leaf = match self.visible_nodes().into_iter().find(|&(node_id, ..)| node_id == id) {
+            Some((_, _, node)) => matches!(node, TreeNode::Leaf(_)),
+            None => return false,
+        };
+
+        match (is_leaf, self.selection_mode) {
+            (false, _) => toggle(&mut self.expanded, id),
+            (true, TreeViewSelectionMode::Single) => self.selected = BTreeSet::from([id]),
+            (true, TreeViewSelectionMode::Multiple) => toggle(&mut self.selected, id),
+        }
+        true
+    }
+
+    fn is_leaf(&self, id: usize) -> bool {
+        matches!(self.flatten().get(id), Some((_, TreeNode::Leaf(_))))
+    }
+
+    /// [`dptree`]-schema for the [`TreeView`] widget
+    ///
+    /// The `on_change` handler is invoked after the leaf is clicked and the
+    /// widget is redrawn. It receives the updated `W` widget
+    pub fn schema<W>(
+        parameters: &'static TreeViewSchemaParameters,
+        on_change: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        W: 'static + Clone + Send + Sync + InlineWidget + WidgetContainer<Self>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_change = on_change.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                Some(TreeNodeId(cq.data?.strip_prefix(parameters.prefix)?.parse().ok()?))
+            })
+            .branch(
+                dptree::filter_map(|mut widget: W, TreeNodeId(id): TreeNodeId| {
+                    widget.get_widget().click(id).then_some(widget)
+                })
+                .chain(update_widget::<W>())
+                .branch(
+                    dptree::filter(|mut widget: W, TreeNodeId(id): TreeNodeId| {
+                        widget.get_widget().is_leaf(id)
+                    })
+                    .branch(on_change),
+                )
+                .endpoint(|| async { Ok(()) }),
+            )
+            .endpoint(|bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                bot.answer_callback_query(cq_id).await?;
+                log::warn!("User clicked on the node which isn't displayed anymore");
+
+                Ok(())
+            })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`TreeView`] widget with
+    /// specified callback query `prefix`.
+    ///
+    /// Only the nodes which aren't hidden within the collapsed branches are
+    /// displayed
+    ///
+    /// It's not supposed to be used directly
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &TreeViewSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup
+    where
+        T: Display,
+    {
+        let tree_view_style = &styles.tree_view_style;
+
+        let keyboard = self
+            .visible_nodes()
+            .into_iter()
+            .map(|(id, depth, node)| {
+                let (icon, label) = match node {
+                    TreeNode::Branch { label, .. } if self.is_expanded(id) => {
+                        (&tree_view_style.expanded_icon, label.clone())
+                    }
+                    TreeNode::Branch { label, .. } => {
+                        (&tree_view_style.collapsed_icon, label.clone())
+                    }
+                    TreeNode::Leaf(item) if self.selected.contains(&id) => {
+                        (&tree_view_style.selected_icon, item.to_string())
+                    }
+                    TreeNode::Leaf(item) => (&tree_view_style.unselected_icon, item.to_string()),
+                };
+                let indent = tree_view_style.indent.repeat(depth);
+                vec![InlineKeyboardButton::callback(
+                    format!("{indent}{icon} {label}"),
+                    format!("{}{id}", parameters.prefix),
+                )]
+            })
+            .collect::<Vec<_>>();
+
+        InlineKeyboardMarkup::new(keyboard)
+    }
+}
+
+/// Inserts the `id` into the `set` or removes it if it's already there
+fn toggle(set: &mut BTreeSet<usize>, id: usize) {
+    if !set.remove(&id) {
+        set.insert(id);
+    }
+}
+
+impl<T> GetSize for TreeView<T> {
+    fn size(&self) -> Size {
+        Size { rows: self.visible_nodes().len().try_into().unwrap_or(u8::MAX), columns: 1 }
+    }
+}
+
+pub struct TreeViewSchemaParameters {
+    /// CallbackQuery data prefix to be sent with the identifier of the clicked
+    /// node
+    pub prefix: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::{types::InlineKeyboardButtonKind, Bot};
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*};
+
+    type Dialogue = TestDialogue<TreeViewWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct TreeViewWidget {
+        #[tree_view(prefix = "t_")]
+        tree_view: TreeView<u8>,
+    }
+
+    fn tree_view() -> TreeView<&'static str> {
+        TreeView::new([
+            TreeNode::branch(
+                "Electronics",
+                [TreeNode::branch("Phones", [TreeNode::leaf("Smartphones")]), TreeNode::leaf("TV")],
+            ),
+            TreeNode::leaf("Books"),
+        ])
+    }
+
+    #[test]
+    fn expanding() {
+        let mut tree_view = tree_view();
+        assert_eq!(tree_view.size(), Size::new(2, 1));
+        // The nested nodes are hidden
+        assert!(!tree_view.click(1));
+
+        assert!(tree_view.click(0));
+        assert!(tree_view.click(1));
+        assert_eq!(tree_view.size(), Size::new(5, 1));
+
+        // The expanded state of the nested branch is kept
+        tree_view.click(0);
+        assert_eq!(tree_view.size(), Size::new(2, 1));
+        tree_view.click(0);
+        assert_eq!(tree_view.size(), Size::new(5, 1));
+
+        tree_view.collapse_all();
+        tree_view.expand_all();
+        assert!(tree_view.is_expanded(1));
+    }
+
+    #[test]
+    fn selection() {
+        let mut tree_view = tree_view();
+        tree_view.expand_all();
+        tree_view.click(2);
+        tree_view.click(4);
+        assert_eq!(tree_view.selected_items().collect::<Vec<_>>(), [&"Books"]);
+
+        tree_view.set_selection_mode(TreeViewSelectionMode::Multiple);
+        tree_view.click(2);
+        tree_view.click(3);
+        assert_eq!(
+            tree_view.selected_items().collect::<Vec<_>>(),
+            [&"Smartphones", &"TV", &"Books"]
+        );
+        tree_view.click(3);
+        assert_eq!(tree_view.selected_item(), Some(&"Smartphones"));
+    }
+
+    #[test]
+    fn markup() {
+        let mut tree_view = tree_view();
+        tree_view.click(0);
+        tree_view.click(3);
+        let parameters = TreeViewSchemaParameters { prefix: "t_" };
+        let keyboard =
+            tree_view.inline_keyboard_markup(&parameters, &WidgetStyles::default()).inline_keyboard;
+        let texts = keyboard.iter().map(|row| row[0].text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["▾ Electronics", "· ▸ Phones", "· ✅ TV", "▫️ Books"]);
+        assert_eq!(keyboard[2][0].kind, InlineKeyboardButtonKind::CallbackData("t_3".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn stale_clicks() {
+        let mut widget = TreeViewWidget {
+            tree_view: TreeView::new([
+                TreeNode::branch("Numbers", [TreeNode::leaf(1), TreeNode::leaf(2)]),
+                TreeNode::leaf(3),
+            ]),
+        };
+        widget.tree_view.click(0);
+        widget.tree_view.click(0);
+        // The leaf was hidden after its branch was collapsed
+        assert!(dispatch(widget.clone(), "t_1").await.is_ignored());
+        // The node doesn't exist
+        assert!(dispatch(widget.clone(), "t_4").await.is_ignored());
+
+        let dispatched = dispatch(widget, "t_3").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().tree_view.selected_item(), Some(&3));
+    }
+
+    #[test]
+    fn size() {
+        let tree_view = TreeView::new(vec![TreeNode::leaf(0); 300]);
+        assert_eq!(tree_view.size(), Size::new(u8::MAX, 1));
+    }
+}
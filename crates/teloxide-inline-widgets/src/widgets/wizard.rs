@@ -0,0 +1,353 @@
+use serde::{Deserialize, Serialize};
+use teloxide::{
+    dispatching::UpdateHandler,
+    dptree,
+    payloads::AnswerCallbackQuerySetters,
+    prelude::Requester,
+    types::{CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{
+    handlers::update_widget,
+    traits::{GetSize, InlineWidget, WidgetContainer},
+    types::{Size, WidgetStyles},
+};
+
+/// Multi-step wizard widget
+///
+/// Being a field of the `user-defined` widget it turns the other fields into
+/// the steps: only the field of the current step is displayed above the
+/// navigation row and receives the callback queries. The steps correspond to
+/// the fields in the declaration order
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Wizard {
+    step: usize,
+    steps: usize,
+}
+
+/// Validation hook of the [`Wizard`] steps, receives the `user-defined` widget
+/// and the index of the current step. The error text is shown to the user
+pub type WizardValidator<C> = fn(&C, usize) -> Result<(), String>;
+
+/// Action performed by a [`Wizard`] navigation button. Used as a unique type in
+/// the [`dptree`]-handler schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardAction {
+    Back,
+    Next,
+    Finish,
+}
+
+impl Wizard {
+    /// Creates new [`Wizard`] instance from the number of the steps, starting
+    /// with the first step
+    pub fn new(steps: usize) -> Self {
+        if steps == 0 {
+            log::warn!("Wizard has no steps");
+        }
+
+        Self { step: 0, steps }
+    }
+
+    /// Returns the index of the current step
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the number of the steps
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Sets the current step by index
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set_step(&mut self, step: usize) {
+        assert!(step < self.steps);
+
+        self.step = step;
+    }
+
+    /// Checks whether the `action` can be performed on the current step, the
+    /// stale buttons of the previous keyboards can be clicked as well
+    fn is_available(&self, action: WizardAction) -> bool {
+        match action {
+            WizardAction::Back => self.step > 0,
+            WizardAction::Next => self.step + 1 < self.steps,
+            WizardAction::Finish => self.step + 1 == self.steps,
+        }
+    }
+
+    /// [`dptree`]-schema for the [`Wizard`] widget
+    ///
+    /// The current step is validated by the `validate` hook before the
+    /// `Next/Finish` button click is handled, the `C` widget is the one which
+    /// contains the [`Wizard`]. The `on_finish` handler is invoked when the
+    /// last step is finished, it receives the `W` widget with the states of
+    /// all the steps and is supposed to answer the callback query, like the
+    /// [`Button`](crate::Button) handler
+    pub fn schema<W, C>(
+        parameters: &'static WizardSchemaParameters,
+        validate: Option<WizardValidator<C>>,
+        on_finish: Option<UpdateHandler<W::Err>>,
+    ) -> UpdateHandler<W::Err>
+    where
+        C: 'static,
+        W: 'static
+            + Clone
+            + Send
+            + Sync
+            + InlineWidget
+            + WidgetContainer<Self>
+            + WidgetContainer<C>,
+        W::Bot: 'static + Clone + Send + Sync,
+        W::Dialogue: 'static + Clone + Send + Sync,
+    {
+        let on_finish = on_finish.unwrap_or_else(dptree::entry);
+
+        dptree::entry()
+            .filter_map(|cq: CallbackQuery| cq.message.map(|msg| (msg.chat.id, msg.id, cq.id)))
+            .filter_map(move |cq: CallbackQuery| {
+                let data = cq.data?;
+                [
+                    (parameters.back_data, WizardAction::Back),
+                    (parameters.next_data, WizardAction::Next),
+                    (parameters.finish_data, WizardAction::Finish),
+                ]
+                .into_iter()
+                .find_map(|(action_data, action)| (data == action_data).then_some(action))
+            })
+            .branch(
+                dptree::filter(move |mut widget: W, action: WizardAction| {
+                    !WidgetContainer::<Self>::get_widget(&mut widget).is_available(action)
+                })
+                .endpoint(
+                    |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                        bot.answer_callback_query(cq_id).await?;
+                        log::warn!("User clicked on the unavailable wizard button");
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter_map(move |mut widget: W, action: WizardAction| {
+                    if action == WizardAction::Back {
+                        return None;
+                    }
+                    let step = WidgetContainer::<Self>::get_widget(&mut widget).step;
+                    validate?(WidgetContainer::<C>::get_widget(&mut widget), step).err()
+                })
+                .endpoint(
+                    |bot: W::Bot,
+                     (_, _, cq_id): (ChatId, MessageId, String),
+                     error: String| async move {
+                        bot.answer_callback_query(cq_id).text(error).await?;
+
+                        Ok(())
+                    },
+                ),
+            )
+            .branch(
+                dptree::filter(|action: WizardAction| action == WizardAction::Finish)
+                    .branch(on_finish)
+                    .endpoint(
+                        |bot: W::Bot, (_, _, cq_id): (ChatId, MessageId, String)| async move {
+                            bot.answer_callback_query(cq_id).await?;
+
+                            Ok(())
+                        },
+                    ),
+            )
+            .map(|mut widget: W, action: WizardAction| {
+                let wizard: &mut Self = widget.get_widget();
+                match action {
+                    WizardAction::Back => wizard.step -= 1,
+                    _ => wizard.step += 1,
+                }
+                widget
+            })
+            .chain(update_widget::<W>())
+            .endpoint(|| async { Ok(()) })
+    }
+
+    /// Creates the [`InlineKeyboardMarkup`] for a [`Wizard`] widget, which is
+    /// the navigation row with the progress indicator
+    ///
+    /// It's not supposed to be used directly
+    ///
+    /// Panics if the number of the steps doesn't match the number of the
+    /// steps of the `user-defined` widget
+    pub fn inline_keyboard_markup(
+        &self,
+        parameters: &WizardSchemaParameters,
+        styles: &WidgetStyles,
+    ) -> InlineKeyboardMarkup {
+        assert_eq!(
+            self.steps, parameters.steps,
+            "Wizard has {} steps, but the widget has {} steps",
+            self.steps, parameters.steps
+        );
+
+        let wizard_style = &styles.wizard_style;
+        let noop_button = |text: &str| InlineKeyboardButton::callback(text, parameters.noop_data);
+
+        let back_button = if self.step > 0 {
+            InlineKeyboardButton::callback(wizard_style.back_text.clone(), parameters.back_data)
+        } else {
+            noop_button(&styles.common_style.empty_cell_icon)
+        };
+        let progress = wizard_style
+            .progress_format
+            .replace("{step}", &(self.step + 1).to_string())
+            .replace("{steps}", &self.steps.to_string());
+        let forward_button = if self.step + 1 < self.steps {
+            InlineKeyboardButton::callback(wizard_style.next_text.clone(), parameters.next_data)
+        } else {
+            InlineKeyboardButton::callback(wizard_style.finish_text.clone(), parameters.finish_data)
+        };
+
+        InlineKeyboardMarkup::new(vec![vec![back_button, noop_button(&progress), forward_button]])
+    }
+}
+
+impl GetSize for Wizard {
+    fn size(&self) -> Size {
+        Size { rows: 1, columns: 3 }
+    }
+}
+
+pub struct WizardSchemaParameters {
+    /// CallbackQuery data to be sent when the `Back` button is clicked
+    pub back_data: &'static str,
+    /// CallbackQuery data to be sent when the `Next` button is clicked
+    pub next_data: &'static str,
+    /// CallbackQuery data to be sent when the `Finish` button is clicked
+    pub finish_data: &'static str,
+    pub noop_data: &'static str,
+    /// Number of the steps of the `user-defined` widget, the [`Wizard`] is
+    /// supposed to have the same number of the steps
+    pub steps: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide::Bot;
+
+    use super::*;
+    use crate::{prelude::*, test_utils::*, CheckboxList, RadioList};
+
+    type Dialogue = TestDialogue<WizardWidget>;
+
+    #[derive(Debug, Clone, InlineWidget)]
+    #[inline_widget(err_ty = Error, bot_ty = Bot, dialogue_ty = Dialogue, state = Some)]
+    struct WizardWidget {
+        #[wizard(prefix = "w_", validate = validate_step, on_finish = finish)]
+        wizard: Wizard,
+        #[radio_list(prefix = "a_")]
+        first: RadioList<u8>,
+        #[checkbox_list(prefix = "b_")]
+        second: CheckboxList<u8>,
+    }
+
+    fn validate_step(widget: &WizardWidget, step: usize) -> Result<(), String> {
+        match step {
+            0 if widget.first.active_item().is_none() => Err("Choose the item".to_owned()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn finish(
+        bot: Bot,
+        (chat_id, _, cq_id): (ChatId, MessageId, String),
+    ) -> Result<(), Error> {
+        bot.answer_callback_query(cq_id).await?;
+        bot.send_message(chat_id, "Finished").await?;
+
+        Ok(())
+    }
+
+    fn wizard_widget() -> WizardWidget {
+        WizardWidget {
+            wizard: Wizard::new(2),
+            first: RadioList::new([1, 2], None, Size::new(1, 2)),
+            second: CheckboxList::new([(false, 1), (false, 2)], Size::new(1, 2)),
+        }
+    }
+
+    #[test]
+    fn navigation_row() {
+        let parameters = WizardSchemaParameters {
+            back_data: "w_b",
+            next_data: "w_n",
+            finish_data: "w_f",
+            noop_data: "noop",
+            steps: 2,
+        };
+        let styles = WidgetStyles::default();
+        let mut wizard = Wizard::new(2);
+
+        let keyboard = wizard.inline_keyboard_markup(&parameters, &styles).inline_keyboard;
+        let texts = keyboard[0].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["✖️", "1/2", "Next ▶️"]);
+
+        wizard.set_step(1);
+        let keyboard = wizard.inline_keyboard_markup(&parameters, &styles).inline_keyboard;
+        let texts = keyboard[0].iter().map(|button| button.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["◀️ Back", "2/2", "✅ Finish"]);
+    }
+
+    #[test]
+    fn available_actions() {
+        let mut wizard = Wizard::new(3);
+        assert!(!wizard.is_available(WizardAction::Back));
+        assert!(wizard.is_available(WizardAction::Next));
+        assert!(!wizard.is_available(WizardAction::Finish));
+
+        wizard.set_step(2);
+        assert!(wizard.is_available(WizardAction::Back));
+        assert!(!wizard.is_available(WizardAction::Next));
+        assert!(wizard.is_available(WizardAction::Finish));
+    }
+
+    #[tokio::test]
+    async fn validation() {
+        let mut widget = wizard_widget();
+
+        let dispatched = dispatch(widget.clone(), "w_n").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery"]);
+        assert_eq!(dispatched.requests[0].body["text"], "Choose the item");
+        assert!(dispatched.widget.is_none());
+
+        widget.first.set_active(0);
+        let dispatched = dispatch(widget, "w_n").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "EditMessageReplyMarkup"]);
+        assert_eq!(dispatched.widget.unwrap().wizard.step(), 1);
+    }
+
+    #[tokio::test]
+    async fn finish_step() {
+        let mut widget = wizard_widget();
+        // The `Finish` button isn't displayed on the first step
+        assert!(dispatch(widget.clone(), "w_f").await.is_ignored());
+
+        widget.wizard.set_step(1);
+        let dispatched = dispatch(widget.clone(), "w_f").await;
+        assert_eq!(dispatched.methods(), ["AnswerCallbackQuery", "SendMessage"]);
+        // The `Next` button isn't displayed on the last step
+        assert!(dispatch(widget, "w_n").await.is_ignored());
+    }
+
+    #[test]
+    #[should_panic]
+    fn steps_mismatch() {
+        let widget = WizardWidget { wizard: Wizard::new(3), ..wizard_widget() };
+        widget.inline_keyboard_markup(&WidgetStyles::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_out_of_bounds() {
+        Wizard::new(2).set_step(2);
+    }
+}